@@ -0,0 +1,58 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use swc_plugin_auto_import::{cached_config, CachedConfig};
+
+/// A config with several large presets, scoped presets and file filters
+const CONFIG: &str = r#"{
+    "imports": [
+        "react",
+        "react-dom",
+        "react-router",
+        "next",
+        "vue",
+        "vue-router",
+        "pinia",
+        "@vueuse/core",
+        { "preset": "vitest", "include": ["**/*.test.ts", "**/*.test.tsx"] },
+        { "from": "@acme/ui", "imports": ["Button", "Card", "Dialog"], "exclude": ["src/server/**"] },
+        { "lodash": [["*", "_"]], "axios": [["default", "axios"]] }
+    ],
+    "exclude": ["**/node_modules/**"],
+    "rsc": true
+}"#;
+
+const FILES: &[&str] = &[
+    "src/app/page.tsx",
+    "src/app/layout.tsx",
+    "src/components/button.test.tsx",
+    "src/server/actions.ts",
+    "src/utils/format.ts",
+];
+
+/// Per-file cost of turning the config string into a normalized config
+///
+/// Measures the native library. The Wasm plugin is instantiated per transform by
+/// the SWC hosts, so it always pays the uncached cost.
+fn bench_config(c: &mut Criterion) {
+    let mut group = c.benchmark_group("config");
+
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for file in FILES {
+                black_box(CachedConfig::parse(black_box(CONFIG)).normalized(Some(file)));
+            }
+        })
+    });
+
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            for file in FILES {
+                black_box(cached_config(black_box(CONFIG)).normalized(Some(file)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_config);
+criterion_main!(benches);
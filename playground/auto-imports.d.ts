@@ -2,14 +2,80 @@
 /* prettier-ignore */
 // @ts-nocheck
 // noinspection JSUnusedGlobalSymbols
-// Generated by unplugin-auto-import
+// Generated by swc-plugin-auto-import
 // biome-ignore lint: disable
 export {}
 
 declare global {
-  const useEffect: typeof import('react').useEffect
-  const useState: typeof import('react').useState
-  const cn: typeof import('twl').cn
+  const Activity: typeof import('react').Activity
+  const Fragment: typeof import('react').Fragment
+  const Link: typeof import('next/link').default
+  const NextImage: typeof import('next/image').default
+  const NextRequest: typeof import('next/server').NextRequest
+  const NextResponse: typeof import('next/server').NextResponse
+  const Script: typeof import('next/script').default
+  const Suspense: typeof import('react').Suspense
   const add: typeof import('@/utils').add
+  const after: typeof import('next/server').after
+  const cache: typeof import('react').cache
+  const cacheSignal: typeof import('react').cacheSignal
+  const cn: typeof import('twl').cn
+  const connection: typeof import('next/server').connection
+  const cookies: typeof import('next/headers').cookies
+  const createContext: typeof import('react').createContext
+  const createPortal: typeof import('react-dom').createPortal
+  const createRef: typeof import('react').createRef
+  const draftMode: typeof import('next/headers').draftMode
+  const dynamic: typeof import('next/dynamic').default
+  const flushSync: typeof import('react-dom').flushSync
+  const forwardRef: typeof import('react').forwardRef
+  const headers: typeof import('next/headers').headers
+  const lazy: typeof import('react').lazy
+  const memo: typeof import('react').memo
   const motion: typeof import('motion/react-m')
-}
\ No newline at end of file
+  const notFound: typeof import('next/navigation').notFound
+  const permanentRedirect: typeof import('next/navigation').permanentRedirect
+  const preconnect: typeof import('react-dom').preconnect
+  const prefetchDNS: typeof import('react-dom').prefetchDNS
+  const preinit: typeof import('react-dom').preinit
+  const preinitModule: typeof import('react-dom').preinitModule
+  const preload: typeof import('react-dom').preload
+  const preloadModule: typeof import('react-dom').preloadModule
+  const redirect: typeof import('next/navigation').redirect
+  const revalidatePath: typeof import('next/cache').revalidatePath
+  const revalidateTag: typeof import('next/cache').revalidateTag
+  const startTransition: typeof import('react').startTransition
+  const use: typeof import('react').use
+  const useActionState: typeof import('react').useActionState
+  const useCallback: typeof import('react').useCallback
+  const useContext: typeof import('react').useContext
+  const useDebugValue: typeof import('react').useDebugValue
+  const useDeferredValue: typeof import('react').useDeferredValue
+  const useEffect: typeof import('react').useEffect
+  const useEffectEvent: typeof import('react').useEffectEvent
+  const useFormStatus: typeof import('react-dom').useFormStatus
+  const useId: typeof import('react').useId
+  const useImperativeHandle: typeof import('react').useImperativeHandle
+  const useInsertionEffect: typeof import('react').useInsertionEffect
+  const useLayoutEffect: typeof import('react').useLayoutEffect
+  const useMemo: typeof import('react').useMemo
+  const useOptimistic: typeof import('react').useOptimistic
+  const useParams: typeof import('next/navigation').useParams
+  const usePathname: typeof import('next/navigation').usePathname
+  const useReducer: typeof import('react').useReducer
+  const useRef: typeof import('react').useRef
+  const useRouter: typeof import('next/navigation').useRouter
+  const useSearchParams: typeof import('next/navigation').useSearchParams
+  const useSelectedLayoutSegment: typeof import('next/navigation').useSelectedLayoutSegment
+  const useSelectedLayoutSegments: typeof import('next/navigation').useSelectedLayoutSegments
+  const useState: typeof import('react').useState
+  const useSyncExternalStore: typeof import('react').useSyncExternalStore
+  const useTransition: typeof import('react').useTransition
+  const userAgent: typeof import('next/server').userAgent
+  // @ts-ignore
+  export type { Metadata, NextConfig, Viewport } from 'next'
+  // @ts-ignore
+  export type { NextRequest, NextResponse } from 'next/server'
+  // @ts-ignore
+  export type { CSSProperties, ChangeEvent, ComponentProps, ComponentPropsWithRef, ComponentPropsWithoutRef, ComponentType, Dispatch, ElementRef, FC, FormEvent, PropsWithChildren, ReactElement, ReactNode, RefObject, SetStateAction } from 'react'
+}
@@ -1,10 +1,13 @@
 import { defineConfig, globalIgnores } from "eslint/config";
 import nextVitals from "eslint-config-next/core-web-vitals";
 import nextTs from "eslint-config-next/typescript";
+import autoImport from "./.eslint-auto-import.json" with { type: "json" };
 
 const eslintConfig = defineConfig([
   ...nextVitals,
   ...nextTs,
+  // Auto imported names, regenerate with `npm run auto-import:eslint`
+  { languageOptions: { globals: autoImport.globals } },
   // Override default ignores of eslint-config-next.
   globalIgnores([
     // Default ignores of eslint-config-next:
@@ -1,4 +1,6 @@
 import type { NextConfig } from 'next'
+// Shared with `swc-auto-import dts` / `swc-auto-import eslint`, see package.json
+import autoImport from './auto-import.config.json'
 
 const nextConfig: NextConfig = {
   typescript: { ignoreBuildErrors: true },
@@ -7,20 +9,7 @@ const nextConfig: NextConfig = {
       [
         'swc-plugin-auto-import',
         {
-          imports: [
-            'react',
-            'react-dom',
-            {
-              "twl": ["cn"]
-            },
-            {
-              "@/utils": ["add"],
-            },
-            {
-              from: 'motion/react-m',
-              imports: [['*', 'motion']],
-            },
-          ],
+          ...autoImport,
           // Set to true to enable debug logging
           debug: true,
         },
@@ -0,0 +1,344 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use serde_json::{Map, Value};
+
+use crate::config::{Arrayable, ImportConfig, InlinePreset, PluginConfig, PresetImport};
+use crate::filter::matches_file;
+use crate::normalize::NormalizedConfig;
+use crate::registry::PresetRegistry;
+use crate::validation::{validate_config, ConfigDiagnostic};
+
+/// Number of configurations kept by `cached_config`
+const MAX_CACHED_CONFIGS: usize = 16;
+
+/// Parsed configurations keyed by the raw config string, oldest first
+///
+/// Only native callers of the library (the CLI, tools linking the rlib) reuse it
+/// across files. The SWC hosts instantiate the Wasm plugin per transform, so
+/// inside the plugin every file starts with an empty cache.
+static CACHE: LazyLock<Mutex<ConfigCache>> = LazyLock::new(Default::default);
+
+#[derive(Default)]
+struct ConfigCache {
+    configs: HashMap<String, Arc<CachedConfig>>,
+    order: VecDeque<String>,
+}
+
+/// Parsed configuration for `config_str`, shared between calls with the same string
+///
+/// At most `MAX_CACHED_CONFIGS` configurations are kept, a long-running host that
+/// keeps generating new config strings evicts the oldest. Preset files are read
+/// once, edits to them are picked up after a restart.
+pub fn cached_config(config_str: &str) -> Arc<CachedConfig> {
+    let mut cache = CACHE.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(cached) = cache.configs.get(config_str) {
+        return cached.clone();
+    }
+
+    if cache.order.len() >= MAX_CACHED_CONFIGS {
+        if let Some(oldest) = cache.order.pop_front() {
+            cache.configs.remove(&oldest);
+        }
+    }
+    let cached = Arc::new(CachedConfig::parse(config_str));
+    cache.order.push_back(config_str.to_string());
+    cache.configs.insert(config_str.to_string(), cached.clone());
+    cached
+}
+
+/// A validated and parsed plugin configuration
+#[derive(Debug)]
+pub struct CachedConfig {
+    /// Problems found by `validate_config`
+    pub diagnostics: Vec<ConfigDiagnostic>,
+    /// Deserialization error, the entries that failed to deserialize are skipped
+    pub parse_error: Option<String>,
+    /// Whether diagnostics should fail the build
+    pub strict: bool,
+    /// Parsed configuration
+    pub config: PluginConfig,
+    registry: PresetRegistry,
+    /// Normalized configs keyed by which glob filters the file matched
+    normalized: Mutex<HashMap<Vec<bool>, Arc<NormalizedConfig>>>,
+    /// Whether the config-level problems were reported already
+    reported: AtomicBool,
+}
+
+impl CachedConfig {
+    /// Validate and parse a config string without caching
+    pub fn parse(config_str: &str) -> Self {
+        // Validate before deserializing: serde ignores unknown keys and drops bad entries
+        let diagnostics = validate_config(config_str);
+        let parsed = serde_json::from_str::<PluginConfig>(config_str);
+        let strict = match &parsed {
+            Ok(config) => config.strict,
+            Err(_) => serde_json::from_str::<serde_json::Value>(config_str)
+                .ok()
+                .and_then(|value| value.get("strict")?.as_bool())
+                .unwrap_or(false),
+        };
+        let (config, parse_error) = match parsed {
+            Ok(config) => (config, None),
+            Err(err) => (parse_lenient(config_str), Some(err.to_string())),
+        };
+        let registry = PresetRegistry::new(&config.custom_presets);
+
+        Self {
+            diagnostics,
+            parse_error,
+            strict,
+            config,
+            registry,
+            normalized: Mutex::default(),
+            reported: AtomicBool::new(false),
+        }
+    }
+
+    /// Mark the diagnostics, parse error and deprecations as reported, false if
+    /// they were reported before
+    ///
+    /// Every file using this config would repeat the same warnings otherwise.
+    pub fn mark_reported(&self) -> bool {
+        !self.reported.swap(true, Ordering::Relaxed)
+    }
+
+    /// Normalized configuration for a file
+    ///
+    /// Files matching the same `include` / `exclude` globs share one instance.
+    pub fn normalized(&self, filename: Option<&str>) -> Arc<NormalizedConfig> {
+        let signature = self.filter_signature(filename);
+        let mut normalized = self
+            .normalized
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        normalized
+            .entry(signature)
+            .or_insert_with(|| {
+                Arc::new(NormalizedConfig::new(
+                    &self.config,
+                    &self.registry,
+                    filename,
+                ))
+            })
+            .clone()
+    }
+
+    /// Which of the config's glob filters `filename` passes, in a stable order
+    fn filter_signature(&self, filename: Option<&str>) -> Vec<bool> {
+        let Some(filename) = filename else {
+            return Vec::new();
+        };
+
+        let imports = match &self.config.imports {
+            Some(Arrayable::Single(item)) => std::slice::from_ref(item),
+            Some(Arrayable::Array(items)) => items.as_slice(),
+            None => &[],
+        };
+
+        let mut signature = vec![matches_file(
+            &self.config.include,
+            &self.config.exclude,
+            filename,
+        )];
+        for import_config in imports.iter().chain(self.registry.definitions()) {
+            match import_config {
+                ImportConfig::InlinePreset(preset) => {
+                    inline_preset_signature(preset, filename, &mut signature);
+                }
+                ImportConfig::ScopedPreset(scoped) => {
+                    signature.push(matches_file(&scoped.include, &scoped.exclude, filename));
+                }
+                _ => {}
+            }
+        }
+        signature
+    }
+}
+
+/// Glob matches of an InlinePreset and its nested presets, depth first
+fn inline_preset_signature(preset: &InlinePreset, filename: &str, signature: &mut Vec<bool>) {
+    signature.push(matches_file(&preset.include, &preset.exclude, filename));
+    for preset_import in &preset.imports {
+        if let PresetImport::Nested(nested) = preset_import {
+            inline_preset_signature(nested, filename, signature);
+        }
+    }
+}
+
+/// Deserialize a config that failed to parse as a whole, skipping the fields and
+/// the array items or map entries that don't deserialize
+fn parse_lenient(config_str: &str) -> PluginConfig {
+    let Ok(Value::Object(object)) = serde_json::from_str::<Value>(config_str) else {
+        return PluginConfig::default();
+    };
+    let parses = |key: &str, value: Value| {
+        serde_json::from_value::<PluginConfig>(Value::Object(Map::from_iter([(
+            key.to_string(),
+            value,
+        )])))
+        .is_ok()
+    };
+
+    let mut valid = Map::new();
+    for (key, value) in object {
+        let value = match value {
+            value if parses(&key, value.clone()) => value,
+            Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .filter(|item| parses(&key, Value::Array(vec![item.clone()])))
+                    .collect(),
+            ),
+            Value::Object(entries) => Value::Object(
+                entries
+                    .into_iter()
+                    .filter(|(name, entry)| {
+                        parses(
+                            &key,
+                            Value::Object(Map::from_iter([(name.clone(), entry.clone())])),
+                        )
+                    })
+                    .collect(),
+            ),
+            _ => continue,
+        };
+        if parses(&key, value.clone()) {
+            valid.insert(key, value);
+        }
+    }
+
+    serde_json::from_value(Value::Object(valid)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_core::atoms::Atom;
+
+    /// Serializes the tests that fill the global cache, so evictions don't race
+    static CACHE_TESTS: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_cached_config() {
+        let _guard = CACHE_TESTS.lock().unwrap_or_else(|err| err.into_inner());
+        let config_str = r#"{ "imports": ["react"], "debug": false }"#;
+        let first = cached_config(config_str);
+        let second = cached_config(config_str);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(
+            &first,
+            &cached_config(r#"{ "imports": ["vue"] }"#)
+        ));
+    }
+
+    #[test]
+    fn test_cache_is_bounded() {
+        let _guard = CACHE_TESTS.lock().unwrap_or_else(|err| err.into_inner());
+        let configs: Vec<String> = (0..=MAX_CACHED_CONFIGS)
+            .map(|i| format!(r#"{{ "imports": [{{ "bounded-{}": ["a"] }}] }}"#, i))
+            .collect();
+        let first = cached_config(&configs[0]);
+        for config in &configs[1..] {
+            cached_config(config);
+        }
+
+        let cache = CACHE.lock().unwrap();
+        assert!(cache.configs.len() <= MAX_CACHED_CONFIGS);
+        assert_eq!(cache.configs.len(), cache.order.len());
+        drop(cache);
+        assert!(!Arc::ptr_eq(&first, &cached_config(&configs[0])));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let cached = CachedConfig::parse(r#"{ "imports": 1, "strict": true }"#);
+        assert!(cached.parse_error.is_some());
+        assert!(cached.strict);
+        assert!(!cached.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_parse_error_skips_invalid_entries() {
+        let cached = CachedConfig::parse(
+            r#"{
+                "imports": ["react", 1, { "@/utils": ["cn"] }, { "from": "x" }],
+                "ignore": ["useId", false],
+                "mergeExisting": "yes",
+                "debug": false
+            }"#,
+        );
+        assert!(cached.parse_error.is_some());
+        assert!(!cached.strict);
+
+        let imports = match &cached.config.imports {
+            Some(Arrayable::Array(items)) => items,
+            _ => panic!("imports should be kept"),
+        };
+        assert_eq!(imports.len(), 2);
+        assert_eq!(cached.config.ignore, ["useId"]);
+        assert!(!cached.config.merge_existing);
+
+        let normalized = cached.normalized(None);
+        assert!(normalized.index.contains_key(&Atom::from("useState")));
+        assert!(normalized.index.contains_key(&Atom::from("cn")));
+        assert!(!normalized.index.contains_key(&Atom::from("useId")));
+    }
+
+    #[test]
+    fn test_nested_preset_filters() {
+        let cached = CachedConfig::parse(
+            r#"{
+                "imports": [{
+                    "from": "outer",
+                    "imports": ["a", { "from": "@/test-utils", "imports": ["render"], "include": ["**/*.test.ts"] }]
+                }]
+            }"#,
+        );
+
+        let source = cached.normalized(Some("src/x.ts"));
+        let test = cached.normalized(Some("src/x.test.ts"));
+        assert!(!Arc::ptr_eq(&source, &test));
+        assert!(!source.index.contains_key(&Atom::from("render")));
+        assert!(test.index.contains_key(&Atom::from("render")));
+        assert!(test.index.contains_key(&Atom::from("a")));
+    }
+
+    #[test]
+    fn test_reported_once() {
+        let cached =
+            CachedConfig::parse(r#"{ "imports": [{ "lodash": ["*"] }], "presets": ["vue"] }"#);
+        assert!(cached.mark_reported());
+        assert!(!cached.mark_reported());
+
+        let normalized = cached.normalized(Some("src/a.ts"));
+        assert_eq!(normalized.warnings()[0].path, "$.imports[0]");
+        assert!(normalized.mark_reported());
+        assert!(!cached.normalized(Some("src/b.ts")).mark_reported());
+    }
+
+    #[test]
+    fn test_normalized_by_filter_signature() {
+        let cached = CachedConfig::parse(
+            r#"{
+                "imports": [
+                    "react",
+                    { "preset": "vitest", "include": ["**/*.test.ts"] }
+                ],
+                "exclude": ["src/generated/**"]
+            }"#,
+        );
+
+        let page = cached.normalized(Some("src/page.ts"));
+        assert!(Arc::ptr_eq(&page, &cached.normalized(Some("src/utils.ts"))));
+
+        let test = cached.normalized(Some("src/page.test.ts"));
+        assert!(!Arc::ptr_eq(&page, &test));
+        assert!(test.index.contains_key(&Atom::from("describe")));
+        assert!(!page.index.contains_key(&Atom::from("describe")));
+
+        let generated = cached.normalized(Some("src/generated/api.ts"));
+        assert!(generated.index.is_empty());
+    }
+}
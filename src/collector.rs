@@ -1,45 +1,235 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use swc_core::atoms::Atom;
+use swc_core::common::{Mark, Span};
 use swc_core::ecma::{
     ast::*,
     visit::{Visit, VisitWith},
 };
 
-/// Identifier collector - collects all identifiers used in the code
+/// Identifier collector - collects free identifiers and module-level bindings
+///
+/// Must run after the resolver pass: an identifier only counts as used when its
+/// `SyntaxContext` carries the `unresolved_mark`, i.e. it does not refer to any
+/// binding in scope. Declarations nested in functions or blocks therefore never
+/// suppress an auto import for the rest of the module.
+///
+/// Usages are split into value references and type references. Identifiers that
+/// are not references at all (labels, export aliases, property signature keys,
+/// intrinsic JSX tags) are ignored.
 pub struct IdentifierCollector {
-    /// Used identifiers
-    pub used_identifiers: HashSet<String>,
-    /// Declared identifiers (functions, variables, classes, etc.)
-    pub declared_identifiers: HashSet<String>,
+    /// Mark applied by the resolver to identifiers without a binding
+    unresolved_mark: Mark,
+    /// Whether the visitor is currently inside a TypeScript type position
+    in_type: bool,
+    /// Whether the visitor is currently inside the name of a `typeof` query
+    in_type_query: bool,
+    /// Identifiers used as values that do not resolve to any binding
+    pub used_identifiers: HashSet<Atom>,
+    /// Identifiers used in type positions that do not resolve to any binding
+    pub type_identifiers: HashSet<Atom>,
+    /// Type position identifiers that are `typeof` queries, which refer to values
+    pub type_query_identifiers: HashSet<Atom>,
+    /// Span of the first unresolved occurrence of each used identifier
+    pub spans: HashMap<Atom, Span>,
+    /// Span of the first unresolved occurrence of each identifier used as a value
+    pub value_spans: HashMap<Atom, Span>,
+    /// Identifiers declared at module scope (functions, variables, classes, etc.)
+    pub declared_identifiers: HashSet<Atom>,
     /// Imported identifiers
-    pub imported_identifiers: HashSet<String>,
+    pub imported_identifiers: HashSet<Atom>,
 }
 
 impl IdentifierCollector {
-    pub fn new() -> Self {
+    pub fn new(unresolved_mark: Mark) -> Self {
         Self {
+            unresolved_mark,
+            in_type: false,
+            in_type_query: false,
             used_identifiers: HashSet::new(),
+            type_identifiers: HashSet::new(),
+            type_query_identifiers: HashSet::new(),
+            spans: HashMap::new(),
+            value_spans: HashMap::new(),
             declared_identifiers: HashSet::new(),
             imported_identifiers: HashSet::new(),
         }
     }
+
+    /// Record the names bound by a module-level declaration
+    fn collect_module_decl(&mut self, decl: &Decl) {
+        match decl {
+            Decl::Fn(func) => {
+                self.declared_identifiers.insert(func.ident.sym.clone());
+            }
+            Decl::Class(class) => {
+                self.declared_identifiers.insert(class.ident.sym.clone());
+            }
+            Decl::Var(var) => {
+                for declarator in &var.decls {
+                    collect_pat_bindings(&declarator.name, &mut self.declared_identifiers);
+                }
+            }
+            Decl::Using(using) => {
+                for declarator in &using.decls {
+                    collect_pat_bindings(&declarator.name, &mut self.declared_identifiers);
+                }
+            }
+            Decl::TsInterface(interface) => {
+                self.declared_identifiers.insert(interface.id.sym.clone());
+            }
+            Decl::TsTypeAlias(alias) => {
+                self.declared_identifiers.insert(alias.id.sym.clone());
+            }
+            Decl::TsEnum(ts_enum) => {
+                self.declared_identifiers.insert(ts_enum.id.sym.clone());
+            }
+            Decl::TsModule(module) => {
+                // `declare global` and `declare module 'x'` do not bind a local name
+                if let TsModuleName::Ident(id) = &module.id {
+                    if !module.global {
+                        self.declared_identifiers.insert(id.sym.clone());
+                    }
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+
+    /// Visit a binding pattern without treating the bound names as usages
+    ///
+    /// Default values, computed keys and type annotations may still reference
+    /// free identifiers, so those parts are visited normally.
+    fn visit_pat_defaults(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Ident(ident) => ident.type_ann.visit_with(self),
+            Pat::Array(array) => self.visit_array_pat_defaults(array),
+            Pat::Object(object) => self.visit_object_pat_defaults(object),
+            Pat::Rest(rest) => self.visit_rest_pat_defaults(rest),
+            Pat::Assign(assign) => {
+                self.visit_pat_defaults(&assign.left);
+                assign.right.visit_with(self);
+            }
+            // Expression patterns only appear in assignment targets, where they are references
+            Pat::Expr(expr) => expr.visit_with(self),
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+
+    fn visit_array_pat_defaults(&mut self, array: &ArrayPat) {
+        for elem in array.elems.iter().flatten() {
+            self.visit_pat_defaults(elem);
+        }
+        array.type_ann.visit_with(self);
+    }
+
+    fn visit_object_pat_defaults(&mut self, object: &ObjectPat) {
+        for prop in &object.props {
+            match prop {
+                ObjectPatProp::KeyValue(key_value) => {
+                    key_value.key.visit_with(self);
+                    self.visit_pat_defaults(&key_value.value);
+                }
+                ObjectPatProp::Assign(assign) => assign.value.visit_with(self),
+                ObjectPatProp::Rest(rest) => self.visit_rest_pat_defaults(rest),
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        }
+        object.type_ann.visit_with(self);
+    }
+
+    fn visit_rest_pat_defaults(&mut self, rest: &RestPat) {
+        self.visit_pat_defaults(&rest.arg);
+        rest.type_ann.visit_with(self);
+    }
+
+    /// Visit a node with every identifier inside it counted as a type reference
+    fn visit_in_type<N: VisitWith<Self>>(&mut self, node: &N) {
+        let in_type = std::mem::replace(&mut self.in_type, true);
+        node.visit_children_with(self);
+        self.in_type = in_type;
+    }
+}
+
+/// Collect every name bound by a (possibly destructuring) pattern
+fn collect_pat_bindings(pat: &Pat, names: &mut HashSet<Atom>) {
+    match pat {
+        Pat::Ident(ident) => {
+            names.insert(ident.id.sym.clone());
+        }
+        Pat::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                collect_pat_bindings(elem, names);
+            }
+        }
+        Pat::Object(object) => {
+            for prop in &object.props {
+                match prop {
+                    ObjectPatProp::KeyValue(key_value) => {
+                        collect_pat_bindings(&key_value.value, names)
+                    }
+                    ObjectPatProp::Assign(assign) => {
+                        names.insert(assign.key.id.sym.clone());
+                    }
+                    ObjectPatProp::Rest(rest) => collect_pat_bindings(&rest.arg, names),
+                    #[allow(unreachable_patterns)]
+                    _ => {}
+                }
+            }
+        }
+        Pat::Rest(rest) => collect_pat_bindings(&rest.arg, names),
+        Pat::Assign(assign) => collect_pat_bindings(&assign.left, names),
+        _ => {}
+    }
 }
 
 impl Visit for IdentifierCollector {
+    // Collect module-level declarations; nested scopes are handled by the resolver
+    fn visit_module(&mut self, module: &Module) {
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => self.collect_module_decl(decl),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                    self.collect_module_decl(&export.decl)
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+                    let ident = match &export.decl {
+                        DefaultDecl::Class(class) => class.ident.as_ref(),
+                        DefaultDecl::Fn(func) => func.ident.as_ref(),
+                        DefaultDecl::TsInterfaceDecl(interface) => Some(&interface.id),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    };
+                    if let Some(ident) = ident {
+                        self.declared_identifiers.insert(ident.sym.clone());
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
+                    self.declared_identifiers.insert(import.id.sym.clone());
+                }
+                _ => {}
+            }
+        }
+        module.visit_children_with(self);
+    }
+
     // Collect imported identifiers
     fn visit_import_decl(&mut self, import: &ImportDecl) {
         for specifier in &import.specifiers {
             match specifier {
                 ImportSpecifier::Named(named) => {
                     let local = &named.local.sym;
-                    self.imported_identifiers.insert(local.to_string());
+                    self.imported_identifiers.insert(local.clone());
                 }
                 ImportSpecifier::Default(default) => {
                     let local = &default.local.sym;
-                    self.imported_identifiers.insert(local.to_string());
+                    self.imported_identifiers.insert(local.clone());
                 }
                 ImportSpecifier::Namespace(ns) => {
                     let local = &ns.local.sym;
-                    self.imported_identifiers.insert(local.to_string());
+                    self.imported_identifiers.insert(local.clone());
                 }
                 #[allow(unreachable_patterns)]
                 _ => {}
@@ -47,27 +237,300 @@ impl Visit for IdentifierCollector {
         }
     }
 
-    // Collect declared identifiers
+    // Binding positions are never usages, whatever their syntax context
     fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
-        if let Pat::Ident(ident) = &declarator.name {
-            self.declared_identifiers.insert(ident.sym.to_string());
+        self.visit_pat_defaults(&declarator.name);
+        declarator.init.visit_with(self);
+    }
+
+    fn visit_param(&mut self, param: &Param) {
+        param.decorators.visit_with(self);
+        self.visit_pat_defaults(&param.pat);
+    }
+
+    fn visit_ts_param_prop(&mut self, prop: &TsParamProp) {
+        prop.decorators.visit_with(self);
+        match &prop.param {
+            TsParamPropParam::Ident(ident) => ident.type_ann.visit_with(self),
+            TsParamPropParam::Assign(assign) => {
+                self.visit_pat_defaults(&assign.left);
+                assign.right.visit_with(self);
+            }
+            #[allow(unreachable_patterns)]
+            _ => {}
         }
-        declarator.visit_children_with(self);
+    }
+
+    fn visit_ts_fn_param(&mut self, param: &TsFnParam) {
+        match param {
+            TsFnParam::Ident(ident) => ident.type_ann.visit_with(self),
+            TsFnParam::Array(array) => self.visit_array_pat_defaults(array),
+            TsFnParam::Rest(rest) => self.visit_rest_pat_defaults(rest),
+            TsFnParam::Object(object) => self.visit_object_pat_defaults(object),
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        for param in &arrow.params {
+            self.visit_pat_defaults(param);
+        }
+        arrow.body.visit_with(self);
+        arrow.type_params.visit_with(self);
+        arrow.return_type.visit_with(self);
+    }
+
+    fn visit_setter_prop(&mut self, setter: &SetterProp) {
+        setter.key.visit_with(self);
+        if let Some(this_param) = &setter.this_param {
+            self.visit_pat_defaults(this_param);
+        }
+        self.visit_pat_defaults(&setter.param);
+        setter.body.visit_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause) {
+        if let Some(param) = &clause.param {
+            self.visit_pat_defaults(param);
+        }
+        clause.body.visit_with(self);
     }
 
     fn visit_fn_decl(&mut self, func: &FnDecl) {
-        self.declared_identifiers.insert(func.ident.sym.to_string());
-        func.visit_children_with(self);
+        func.function.visit_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, func: &FnExpr) {
+        func.function.visit_with(self);
     }
 
     fn visit_class_decl(&mut self, class: &ClassDecl) {
-        self.declared_identifiers
-            .insert(class.ident.sym.to_string());
-        class.visit_children_with(self);
+        class.class.visit_with(self);
+    }
+
+    fn visit_class_expr(&mut self, class: &ClassExpr) {
+        class.class.visit_with(self);
+    }
+
+    fn visit_ts_interface_decl(&mut self, interface: &TsInterfaceDecl) {
+        interface.type_params.visit_with(self);
+        interface.extends.visit_with(self);
+        interface.body.visit_with(self);
     }
 
-    // Collect used identifiers
+    fn visit_ts_type_alias_decl(&mut self, alias: &TsTypeAliasDecl) {
+        alias.type_params.visit_with(self);
+        alias.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_type_param(&mut self, param: &TsTypeParam) {
+        param.constraint.visit_with(self);
+        param.default.visit_with(self);
+    }
+
+    fn visit_ts_enum_decl(&mut self, ts_enum: &TsEnumDecl) {
+        for member in &ts_enum.members {
+            member.init.visit_with(self);
+        }
+    }
+
+    fn visit_ts_module_decl(&mut self, module: &TsModuleDecl) {
+        module.body.visit_with(self);
+    }
+
+    fn visit_ts_namespace_decl(&mut self, namespace: &TsNamespaceDecl) {
+        namespace.body.visit_with(self);
+    }
+
+    fn visit_ts_import_equals_decl(&mut self, import: &TsImportEqualsDecl) {
+        import.module_ref.visit_with(self);
+    }
+
+    // Type positions: annotations, `typeof` queries, `implements` and interface `extends`
+    fn visit_ts_type(&mut self, ty: &TsType) {
+        self.visit_in_type(ty);
+    }
+
+    fn visit_ts_expr_with_type_args(&mut self, expr: &TsExprWithTypeArgs) {
+        self.visit_in_type(expr);
+    }
+
+    fn visit_ts_type_query(&mut self, query: &TsTypeQuery) {
+        let in_type_query = std::mem::replace(&mut self.in_type_query, true);
+        query.expr_name.visit_with(self);
+        self.in_type_query = in_type_query;
+        query.type_args.visit_with(self);
+    }
+
+    // Non-references: labels, export aliases, signature keys and intrinsic JSX tags
+    fn visit_labeled_stmt(&mut self, stmt: &LabeledStmt) {
+        stmt.body.visit_with(self);
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt) {}
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt) {}
+
+    fn visit_named_export(&mut self, export: &NamedExport) {
+        // Re-exports (`export { a } from 'x'`) do not reference local bindings
+        if export.src.is_some() {
+            return;
+        }
+        for specifier in &export.specifiers {
+            if let ExportSpecifier::Named(named) = specifier {
+                if export.type_only || named.is_type_only {
+                    self.visit_in_type(&named.orig);
+                } else {
+                    named.orig.visit_with(self);
+                }
+            }
+        }
+    }
+
+    fn visit_ts_namespace_export_decl(&mut self, _: &TsNamespaceExportDecl) {}
+
+    fn visit_ts_property_signature(&mut self, signature: &TsPropertySignature) {
+        if signature.computed {
+            signature.key.visit_with(self);
+        }
+        signature.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_method_signature(&mut self, signature: &TsMethodSignature) {
+        if signature.computed {
+            signature.key.visit_with(self);
+        }
+        signature.type_params.visit_with(self);
+        signature.params.visit_with(self);
+        signature.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_getter_signature(&mut self, signature: &TsGetterSignature) {
+        if signature.computed {
+            signature.key.visit_with(self);
+        }
+        signature.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_setter_signature(&mut self, signature: &TsSetterSignature) {
+        if signature.computed {
+            signature.key.visit_with(self);
+        }
+        signature.param.visit_with(self);
+    }
+
+    fn visit_ts_type_predicate(&mut self, predicate: &TsTypePredicate) {
+        predicate.type_ann.visit_with(self);
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+        if let JSXElementName::Ident(ident) = name {
+            // Lowercase and dashed tags are intrinsic elements, not component references
+            if ident.sym.starts_with(|c: char| c.is_ascii_lowercase()) || ident.sym.contains('-') {
+                return;
+            }
+        }
+        name.visit_children_with(self);
+    }
+
+    // Collect used identifiers that the resolver could not bind
     fn visit_ident(&mut self, ident: &Ident) {
-        self.used_identifiers.insert(ident.sym.to_string());
+        if ident.ctxt.outer() == self.unresolved_mark {
+            let name = ident.sym.clone();
+            self.spans.entry(name.clone()).or_insert(ident.span);
+            if self.in_type {
+                if self.in_type_query {
+                    self.type_query_identifiers.insert(name.clone());
+                }
+                self.type_identifiers.insert(name);
+            } else {
+                self.value_spans.entry(name.clone()).or_insert(ident.span);
+                self.used_identifiers.insert(name);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_core::common::{FileName, SourceMap, SyntaxContext, GLOBALS};
+    use swc_core::ecma::parser::{parse_file_as_module, Syntax, TsSyntax};
+    use swc_core::ecma::visit::{VisitMut, VisitMutWith};
+
+    /// Gives every identifier the unresolved mark, so only the collector's own
+    /// handling of binding positions keeps bindings out of the used sets
+    struct MarkUnresolved(SyntaxContext);
+
+    impl VisitMut for MarkUnresolved {
+        fn visit_mut_ident(&mut self, ident: &mut Ident) {
+            ident.ctxt = self.0;
+        }
+    }
+
+    fn collect(source: &str) -> IdentifierCollector {
+        GLOBALS.set(&Default::default(), || {
+            let cm = SourceMap::default();
+            let fm = cm.new_source_file(FileName::Anon.into(), source.to_string());
+            let syntax = Syntax::Typescript(TsSyntax::default());
+            let mut module =
+                parse_file_as_module(&fm, syntax, EsVersion::latest(), None, &mut vec![]).unwrap();
+
+            let unresolved_mark = Mark::new();
+            let ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
+            module.visit_mut_with(&mut MarkUnresolved(ctxt));
+
+            let mut collector = IdentifierCollector::new(unresolved_mark);
+            module.visit_with(&mut collector);
+            collector
+        })
+    }
+
+    fn sorted(names: &HashSet<Atom>) -> Vec<&str> {
+        let mut names: Vec<_> = names.iter().map(|name| name.as_str()).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_bindings_are_not_usages() {
+        let collector = collect(
+            r#"
+            const { ref, nested: [computed, ...rest] } = createStore();
+            function useCounter(watch, { reactive = shallowRef() }, ...unref) {}
+            const onChange = (toRef = 1) => {};
+            try {} catch ({ isRef }) {}
+            enum toRaw { On }
+            "#,
+        );
+
+        assert_eq!(
+            sorted(&collector.used_identifiers),
+            ["createStore", "shallowRef"]
+        );
+        assert_eq!(
+            sorted(&collector.declared_identifiers),
+            ["computed", "onChange", "ref", "rest", "toRaw", "useCounter"]
+        );
+    }
+
+    #[test]
+    fn test_spans_point_at_first_usage() {
+        let collector = collect("useState(useEffect, useState);");
+
+        let first = collector.spans[&Atom::from("useState")];
+        let second = collector.spans[&Atom::from("useEffect")];
+        assert!(first.lo < second.lo);
+        assert_eq!((first.hi - first.lo).0, "useState".len() as u32);
+    }
+
+    #[test]
+    fn test_value_spans_skip_type_usages() {
+        let collector = collect("type Setter = ReturnType<typeof useState>; useState(0);");
+
+        let first = collector.spans[&Atom::from("useState")];
+        let value = collector.value_spans[&Atom::from("useState")];
+        assert!(first.lo < value.lo);
     }
 }
@@ -57,7 +57,7 @@ pub enum PresetImport {
 
 /// InlinePreset - defines imports from a single module
 ///
-/// Corresponds to: { from: ModuleId, type?: boolean, imports: (PresetImport | InlinePreset)[] }
+/// Corresponds to: { from: ModuleId, type?: boolean, imports: (PresetImport | InlinePreset)[], include?: string[], exclude?: string[], priority?: number }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InlinePreset {
     /// Module specifier to import from
@@ -68,11 +68,44 @@ pub struct InlinePreset {
     pub type_only: Option<bool>,
     /// List of imports from this module
     pub imports: Vec<PresetImport>,
+    /// Only apply to files matching one of these globs
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Skip files matching one of these globs
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Wins conflicts over entries with a lower priority, only read on top-level entries
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+}
+
+/// ScopedPreset - a preset that only applies to some files
+///
+/// Corresponds to: { preset: PresetName, include?: string[], exclude?: string[], priority?: number }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopedPreset {
+    /// Built-in or custom preset name
+    pub preset: String,
+    /// Only apply to files matching one of these globs
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Skip files matching one of these globs
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Wins conflicts over entries with a lower priority
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
 }
 
 /// Import configuration item - supports multiple formats
 ///
-/// Corresponds to: ImportsMap | PresetName | InlinePreset
+/// Corresponds to: ImportsMap | PresetName | InlinePreset | ScopedPreset
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ImportConfig {
@@ -80,6 +113,9 @@ pub enum ImportConfig {
     /// Must be checked first due to the 'from' field being the distinguishing factor
     InlinePreset(InlinePreset),
 
+    /// ScopedPreset: { preset: "vitest", include: ["**/*.test.ts"] }
+    ScopedPreset(ScopedPreset),
+
     /// Explicit form with individual import items (legacy support)
     /// Example: [{ name: "ref", from: "vue" }, { name: "useState", as: "useSignal", from: "react" }]
     Explicit(Vec<ExplicitImport>),
@@ -97,6 +133,17 @@ pub enum ImportConfig {
     PresetName(String),
 }
 
+impl ImportConfig {
+    /// Conflict priority of the entry, 0 unless set
+    pub fn priority(&self) -> i32 {
+        match self {
+            ImportConfig::InlinePreset(preset) => preset.priority.unwrap_or(0),
+            ImportConfig::ScopedPreset(preset) => preset.priority.unwrap_or(0),
+            _ => 0,
+        }
+    }
+}
+
 /// Arrayable type - supports both single value and array
 ///
 /// Corresponds to: type Arrayable<T> = T | Array<T>
@@ -107,8 +154,61 @@ pub enum Arrayable<T> {
     Array(Vec<T>),
 }
 
-/// Plugin configuration
+/// A preset defined in the config and referenced by name like a built-in
+///
+/// Corresponds to: string | Arrayable<ImportsMap | PresetName | InlinePreset>
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PresetDefinition {
+    /// Path to a JSON file holding the definition: "./presets/design-system.json"
+    File(String),
+    /// Inline definition, same shape as `imports`
+    Inline(Arrayable<ImportConfig>),
+}
+
+/// Auto imports permitted in modules with a given directive
+///
+/// Entries match the export name (`"useState"`), the local name it is imported as,
+/// or the import source (`"react-dom"`).
+///
+/// Corresponds to: { allow?: string[], deny?: string[] }
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DirectiveFilter {
+    /// Only these imports may be added, everything else is reported
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+    /// These imports are reported instead of added
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl DirectiveFilter {
+    /// Whether an import of the export `name` from `source`, as `local_name`, is permitted
+    pub fn permits(&self, name: &str, local_name: &str, source: &str) -> bool {
+        let matches = |entry: &String| entry == name || entry == local_name || entry == source;
+        let allowed = self
+            .allow
+            .as_ref()
+            .is_none_or(|allow| allow.iter().any(matches));
+
+        allowed && !self.deny.iter().any(matches)
+    }
+}
+
+/// How to resolve a local name that several sources provide
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    /// Import from the entry with the highest `priority`, then the earliest in `imports`
+    #[default]
+    Priority,
+    /// Report the conflict and import the name from none of the sources
+    Error,
+}
+
+/// Plugin configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PluginConfig {
     /// Import configurations: can be a single item or array of ImportsMap | PresetName | InlinePreset
     ///
@@ -116,16 +216,65 @@ pub struct PluginConfig {
     #[serde(default)]
     pub imports: Option<Arrayable<ImportConfig>>,
 
+    /// Custom presets by name, referenced from `imports` like the built-ins
+    ///
+    /// Corresponds to: customPresets?: Record<string, string | Arrayable<ImportsMap | PresetName | InlinePreset>>
+    #[serde(default)]
+    #[serde(rename = "customPresets")]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub custom_presets: HashMap<String, PresetDefinition>,
+
+    /// Legacy preset names, e.g. `["vue"]`, from older unplugin-auto-import configs
+    /// (deprecated, list them in `imports` instead)
+    ///
+    /// Corresponds to: presets?: Arrayable<PresetName>
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presets: Option<Arrayable<String>>,
+
+    /// Append auto imports to an existing import declaration from the same source
+    /// instead of emitting a new one
+    #[serde(default)]
+    #[serde(rename = "mergeExisting")]
+    pub merge_existing: bool,
+
+    /// Local names that are never auto imported
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+
+    /// How to resolve a local name that several sources provide
+    #[serde(default)]
+    pub conflicts: ConflictPolicy,
+
+    /// Only transform files matching one of these globs, relative to the working directory
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
+    /// Skip files matching one of these globs, e.g. `["**/node_modules/**"]`
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// Report client-only React and Next.js APIs in `"use server"` and `"use cache"`
+    /// modules instead of importing them
+    #[serde(default)]
+    pub rsc: bool,
+
+    /// Allow/deny lists keyed by module directive (`"use client"`, `"use server"`),
+    /// `"default"` applies to modules without a directive
+    ///
+    /// Corresponds to: directives?: Record<string, { allow?: string[], deny?: string[] }>
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub directives: HashMap<String, DirectiveFilter>,
+
+    /// Treat configuration problems as errors and abort instead of warning
+    #[serde(default)]
+    pub strict: bool,
+
     /// Enable debug logging
     #[serde(default)]
     pub debug: bool,
 }
-
-impl Default for PluginConfig {
-    fn default() -> Self {
-        Self {
-            imports: None,
-            debug: false,
-        }
-    }
-}
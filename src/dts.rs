@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::config::PluginConfig;
+use crate::normalize::{is_identifier, ExportKind, NormalizedConfig};
+use crate::registry::PresetRegistry;
+
+/// Generate a TypeScript declaration file that declares every auto imported name
+/// as a global
+///
+/// Value exports become `const` declarations, type-only exports are re-exported
+/// with `export type`. A name with both kinds declares the winner of each. The
+/// `include` / `exclude` globs are ignored, so names scoped to some files (e.g.
+/// test globals) are declared too.
+pub fn generate_dts(config: &PluginConfig) -> String {
+    let registry = PresetRegistry::new(&config.custom_presets);
+    render_dts(&NormalizedConfig::new(config, &registry, None))
+}
+
+/// Render the declaration file of a normalized configuration
+pub(crate) fn render_dts(config: &NormalizedConfig) -> String {
+    let mut dts = String::from(
+        "/* eslint-disable */\n\
+         /* prettier-ignore */\n\
+         // @ts-nocheck\n\
+         // noinspection JSUnusedGlobalSymbols\n\
+         // Generated by swc-plugin-auto-import\n\
+         // biome-ignore lint: disable\n\
+         export {}\n\
+         \n\
+         declare global {\n",
+    );
+
+    // Type exports are re-exported rather than aliased: `type Props = import('react').Props`
+    // would drop the generic parameters of `Props<T>`
+    let mut type_exports: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+
+    for (local_name, export) in config.resolved_exports() {
+        if export.kind == ExportKind::Type && export.name != "*" {
+            let specifier = if export.name == local_name.as_str() {
+                export.name.clone()
+            } else {
+                format!("{} as {}", export.name, local_name)
+            };
+            type_exports
+                .entry(&export.source)
+                .or_default()
+                .push(specifier);
+            continue;
+        }
+
+        let member = match export.name.as_str() {
+            "*" => String::new(),
+            name if is_identifier(name) => format!(".{}", name),
+            name => format!("['{}']", escape(name)),
+        };
+        let _ = writeln!(
+            dts,
+            "  const {}: typeof import('{}'){}",
+            local_name,
+            escape(&export.source),
+            member
+        );
+    }
+
+    for (source, specifiers) in type_exports {
+        let _ = writeln!(
+            dts,
+            "  // @ts-ignore\n  export type {{ {} }} from '{}'",
+            specifiers.join(", "),
+            escape(source)
+        );
+    }
+
+    dts.push_str("}\n");
+    dts
+}
+
+/// Escape a string for a single-quoted TypeScript string literal
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dts(config: &str) -> String {
+        generate_dts(&serde_json::from_str(config).unwrap())
+    }
+
+    #[test]
+    fn test_generate_dts() {
+        let dts = dts(r#"{
+            "imports": [
+                { "react": ["useState", ["useEffect", "useMount"]] },
+                { "axios": [["default", "axios"]], "motion/react-m": [["*", "motion"]] },
+                { "from": "vue", "type": true, "imports": ["Ref", ["Component", "VueComponent"]] },
+                { "preset": "vitest", "include": ["**/*.test.ts"] }
+            ],
+            "ignore": ["vi"]
+        }"#);
+
+        assert!(dts.starts_with("/* eslint-disable */\n"));
+        assert!(dts.contains("\nexport {}\n\ndeclare global {\n"));
+        assert!(dts.contains("  const useState: typeof import('react').useState\n"));
+        assert!(dts.contains("  const useMount: typeof import('react').useEffect\n"));
+        assert!(dts.contains("  const axios: typeof import('axios').default\n"));
+        assert!(dts.contains("  const motion: typeof import('motion/react-m')\n"));
+        assert!(dts.contains("  export type { Ref, Component as VueComponent } from 'vue'\n"));
+        assert!(dts.contains("  const describe: typeof import('vitest').describe\n"));
+        assert!(!dts.contains(" vi:"));
+        assert!(dts.ends_with("}\n"));
+
+        // Sorted by local name
+        assert!(dts.find("axios:").unwrap() < dts.find("useState:").unwrap());
+    }
+
+    #[test]
+    fn test_conflicts_declare_winner() {
+        let dts = dts(r#"{
+            "imports": [
+                { "from": "@acme/ui", "imports": ["Button"] },
+                { "from": "@acme/legacy", "imports": ["Button"], "priority": 1 },
+                { "from": "@acme/ui", "type": true, "imports": ["Button"] }
+            ]
+        }"#);
+
+        assert!(dts.contains("  const Button: typeof import('@acme/legacy').Button\n"));
+        assert!(dts.contains("  export type { Button } from '@acme/ui'\n"));
+        assert_eq!(dts.matches("Button").count(), 3);
+    }
+
+    #[test]
+    fn test_conflict_error_skips_refused_names() {
+        let dts = dts(r#"{
+            "imports": [
+                { "ahooks": ["useDebounce", "useRequest"] },
+                { "@vueuse/core": ["useDebounce"] },
+                { "from": "lodash-es", "imports": ["debounce"], "priority": 1 },
+                { "es-toolkit": ["debounce"] }
+            ],
+            "conflicts": "error"
+        }"#);
+
+        assert!(!dts.contains("useDebounce"));
+        assert!(dts.contains("  const useRequest: typeof import('ahooks').useRequest\n"));
+        assert!(dts.contains("  const debounce: typeof import('lodash-es').debounce\n"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape(r"it's\"), r"it\'s\\");
+    }
+}
@@ -0,0 +1,74 @@
+use glob_match::glob_match;
+
+/// Whether a file passes `include` / `exclude` glob lists
+///
+/// An empty `include` list includes every file. `exclude` wins over `include`.
+pub(crate) fn matches_file(include: &[String], exclude: &[String], filename: &str) -> bool {
+    let matches = |pattern: &String| glob_match(pattern, filename);
+
+    (include.is_empty() || include.iter().any(matches)) && !exclude.iter().any(matches)
+}
+
+/// Normalize a filename for glob matching
+///
+/// Paths use forward slashes and are made relative to `cwd` when the file lives
+/// inside it, so patterns like `src/**` work with the absolute paths SWC reports.
+pub fn relative_filename(filename: &str, cwd: Option<&str>) -> String {
+    let filename = filename.replace('\\', "/");
+    let Some(cwd) = cwd.map(|cwd| cwd.replace('\\', "/")) else {
+        return filename;
+    };
+
+    match filename.strip_prefix(cwd.trim_end_matches('/')) {
+        Some(relative) if relative.starts_with('/') => relative[1..].to_string(),
+        _ => filename,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn globs(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_include_exclude() {
+        let include = globs(&["**/*.test.ts", "**/*.spec.ts"]);
+        assert!(matches_file(&include, &[], "src/utils.test.ts"));
+        assert!(matches_file(&include, &[], "utils.spec.ts"));
+        assert!(!matches_file(&include, &[], "src/utils.ts"));
+
+        let exclude = globs(&["**/node_modules/**", "src/generated/**"]);
+        assert!(matches_file(&[], &exclude, "src/app/page.tsx"));
+        assert!(!matches_file(&[], &exclude, "node_modules/react/index.js"));
+        assert!(!matches_file(&[], &exclude, "src/generated/api.ts"));
+        assert!(!matches_file(
+            &include,
+            &exclude,
+            "src/generated/api.test.ts"
+        ));
+    }
+
+    #[test]
+    fn test_relative_filename() {
+        assert_eq!(
+            relative_filename("/home/app/src/page.tsx", Some("/home/app")),
+            "src/page.tsx"
+        );
+        assert_eq!(
+            relative_filename("/home/app/src/page.tsx", Some("/home/app/")),
+            "src/page.tsx"
+        );
+        assert_eq!(
+            relative_filename("/home/application/page.tsx", Some("/home/app")),
+            "/home/application/page.tsx"
+        );
+        assert_eq!(
+            relative_filename(r"C:\app\src\page.tsx", Some(r"C:\app")),
+            "src/page.tsx"
+        );
+        assert_eq!(relative_filename("src/page.tsx", None), "src/page.tsx");
+    }
+}
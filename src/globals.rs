@@ -0,0 +1,109 @@
+use serde_json::{json, Map, Value};
+
+use crate::config::PluginConfig;
+use crate::normalize::{ExportKind, NormalizedConfig};
+use crate::registry::PresetRegistry;
+
+/// Generate an ESLint globals file declaring every auto imported value as a
+/// read-only global
+///
+/// The `globals` object works in flat configs (`languageOptions.globals`) and
+/// in legacy `.eslintrc` files (`extends`). Type-only imports are left out.
+pub fn generate_eslint_globals(config: &PluginConfig) -> String {
+    let names = global_names(config);
+    let globals: Map<String, Value> = names
+        .into_iter()
+        .map(|name| (name, Value::from("readonly")))
+        .collect();
+
+    to_json(json!({ "globals": globals }))
+}
+
+/// Generate a Biome config snippet listing every auto imported value in
+/// `javascript.globals`
+pub fn generate_biome_globals(config: &PluginConfig) -> String {
+    to_json(json!({ "javascript": { "globals": global_names(config) } }))
+}
+
+/// Local names of the auto imported values, sorted
+///
+/// Every `include` / `exclude` glob passes, like the generated declarations.
+/// Names that `conflicts: "error"` refuses to import are left out.
+fn global_names(config: &PluginConfig) -> Vec<String> {
+    let registry = PresetRegistry::new(&config.custom_presets);
+    NormalizedConfig::new(config, &registry, None)
+        .resolved_exports()
+        .into_iter()
+        .filter(|(_, export)| export.kind == ExportKind::Value)
+        .map(|(local_name, _)| local_name.to_string())
+        .collect()
+}
+
+fn to_json(value: Value) -> String {
+    let mut json = serde_json::to_string_pretty(&value).unwrap_or_default();
+    json.push('\n');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> PluginConfig {
+        serde_json::from_str(
+            r#"{
+                "imports": [
+                    { "react": ["useState", ["useEffect", "useMount"]] },
+                    { "from": "react", "type": true, "imports": ["FC"] },
+                    { "preset": "vitest", "include": ["**/*.test.ts"] }
+                ],
+                "ignore": ["vi"]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_eslint_globals() {
+        let globals: Value = serde_json::from_str(&generate_eslint_globals(&config())).unwrap();
+        let globals = globals["globals"].as_object().unwrap();
+
+        assert_eq!(globals["useState"], "readonly");
+        assert_eq!(globals["useMount"], "readonly");
+        assert_eq!(globals["describe"], "readonly");
+        assert!(!globals.contains_key("useEffect"));
+        assert!(!globals.contains_key("FC"));
+        assert!(!globals.contains_key("vi"));
+    }
+
+    #[test]
+    fn test_conflict_error_skips_refused_names() {
+        let config = serde_json::from_str(
+            r#"{
+                "imports": [{ "ahooks": ["useDebounce"] }, { "@vueuse/core": ["useDebounce", "useMouse"] }],
+                "conflicts": "error"
+            }"#,
+        )
+        .unwrap();
+        let globals: Value = serde_json::from_str(&generate_eslint_globals(&config)).unwrap();
+        let globals = globals["globals"].as_object().unwrap();
+
+        assert!(!globals.contains_key("useDebounce"));
+        assert_eq!(globals["useMouse"], "readonly");
+    }
+
+    #[test]
+    fn test_biome_globals() {
+        let biome: Value = serde_json::from_str(&generate_biome_globals(&config())).unwrap();
+        let globals = biome["javascript"]["globals"].as_array().unwrap();
+
+        assert!(globals.contains(&Value::from("useState")));
+        assert!(!globals.contains(&Value::from("FC")));
+
+        // Sorted by local name
+        let names: Vec<_> = globals.iter().filter_map(Value::as_str).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
+}
@@ -1,20 +1,38 @@
 use std::sync::Arc;
-use swc_core::common::SourceMap;
+use swc_core::common::{plugin::metadata::TransformPluginMetadataContextKind, SourceMap};
 use swc_core::ecma::{
     ast::Program, codegen::text_writer::JsWriter, codegen::Emitter, visit::VisitMutWith,
 };
-use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata};
+use swc_core::plugin::{
+    errors::HANDLER, plugin_transform, proxies::TransformPluginProgramMetadata,
+};
 
+mod cache;
 mod collector;
 mod config;
+mod dts;
+mod filter;
+mod globals;
+mod normalize;
+mod pragma;
 mod presets;
+mod registry;
+mod validation;
 mod visitor;
 
+pub use cache::{cached_config, CachedConfig};
 pub use config::{
-    Arrayable, ExplicitImport, ImportConfig, ImportItem, ImportSource, InlinePreset, PluginConfig,
-    PresetImport,
+    Arrayable, ConflictPolicy, DirectiveFilter, ExplicitImport, ImportConfig, ImportItem,
+    ImportSource, InlinePreset, PluginConfig, PresetDefinition, PresetImport, ScopedPreset,
 };
-pub use visitor::AutoImportVisitor;
+pub use dts::generate_dts;
+pub use filter::relative_filename;
+pub use globals::{generate_biome_globals, generate_eslint_globals};
+pub use normalize::{NormalizedConfig, ResolvedImport};
+pub use pragma::Pragmas;
+pub use registry::PresetRegistry;
+pub use validation::{validate_config, ConfigDiagnostic};
+pub use visitor::{AutoImportVisitor, ImportDiagnostic};
 
 /// Convert Program AST to source code string for debugging
 fn program_to_string(program: &Program) -> String {
@@ -72,7 +90,44 @@ pub fn process_transform(
     let config_str = metadata
         .get_transform_plugin_config()
         .unwrap_or_else(|| "{}".to_string());
-    let config = serde_json::from_str::<PluginConfig>(&config_str).unwrap_or_default();
+
+    // Cached per config string, which only helps hosts that reuse the plugin instance
+    let cached = cached_config(&config_str);
+    let strict = cached.strict;
+
+    let config = &cached.config;
+
+    // Config-level problems are the same for every file, report them once
+    if cached.mark_reported() {
+        HANDLER.with(|handler| {
+            for diagnostic in &cached.diagnostics {
+                let message = format!("[swc-plugin-auto-import] invalid config at {}", diagnostic);
+                if strict {
+                    handler.err(&message);
+                } else {
+                    handler.warn(&message);
+                }
+            }
+
+            if let Some(err) = &cached.parse_error {
+                handler.warn(&format!(
+                    "[swc-plugin-auto-import] failed to parse config, skipping invalid entries: {}",
+                    err
+                ));
+            }
+
+            if config.presets.is_some() {
+                handler.warn(
+                    "[swc-plugin-auto-import] `presets` is deprecated, list preset names in `imports` instead",
+                );
+            }
+        });
+    }
+
+    // Strict mode aborts the build: errors were reported, leave the program untouched
+    if strict && !cached.diagnostics.is_empty() {
+        return program;
+    }
 
     let debug = config.debug;
 
@@ -86,11 +141,52 @@ pub fn process_transform(
     // Get unresolved_mark for proper syntax context
     let unresolved_mark = metadata.unresolved_mark;
 
+    // Globs are matched against the filename relative to the working directory
+    let filename = metadata
+        .get_context(&TransformPluginMetadataContextKind::Filename)
+        .map(|filename| {
+            let cwd = metadata.get_context(&TransformPluginMetadataContextKind::Cwd);
+            filter::relative_filename(&filename, cwd.as_deref())
+        });
+
     // Apply transformation only to Module programs
     match &mut program {
         Program::Module(module) => {
-            let mut visitor = AutoImportVisitor::new(config, unresolved_mark);
+            let pragmas = metadata
+                .comments
+                .as_ref()
+                .map(|comments| Pragmas::from_comments(comments, module))
+                .unwrap_or_default();
+            let normalized = cached.normalized(filename.as_deref());
+            if normalized.mark_reported() {
+                HANDLER.with(|handler| {
+                    for warning in normalized.warnings() {
+                        handler.warn(&format!(
+                            "[swc-plugin-auto-import] config warning at {}",
+                            warning
+                        ));
+                    }
+                });
+            }
+
+            let mut visitor = AutoImportVisitor::from_normalized(normalized, unresolved_mark)
+                .with_pragmas(pragmas);
             module.visit_mut_with(&mut visitor);
+
+            // Client-only APIs fail at runtime in server modules and ambiguous names
+            // need a decision, fail the build instead
+            if !visitor.diagnostics().is_empty() {
+                HANDLER.with(|handler| {
+                    for diagnostic in visitor.diagnostics() {
+                        handler
+                            .struct_span_err(
+                                diagnostic.span,
+                                &format!("[swc-plugin-auto-import] {}", diagnostic),
+                            )
+                            .emit();
+                    }
+                });
+            }
         }
         _ => {
             // Scripts don't support imports, skip transformation
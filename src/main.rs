@@ -0,0 +1,345 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use clap::{Args, Parser, Subcommand};
+use swc_core::common::{comments::SingleThreadedComments, FileName, Mark, SourceMap, GLOBALS};
+use swc_core::ecma::{
+    ast::EsVersion,
+    codegen::{text_writer::JsWriter, Config as CodegenConfig, Emitter},
+    parser::{parse_file_as_module, EsSyntax, Syntax, TsSyntax},
+    transforms::base::resolver,
+    visit::VisitMutWith,
+};
+use swc_plugin_auto_import::{
+    generate_biome_globals, generate_dts, generate_eslint_globals, relative_filename,
+    AutoImportVisitor, CachedConfig, ConflictPolicy, PluginConfig, Pragmas, ResolvedImport,
+};
+
+/// Inspect a swc-plugin-auto-import config and generate code from it
+#[derive(Parser)]
+#[command(name = "swc-auto-import", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the resolved import map as import statements
+    List(FileArgs),
+    /// Validate the config and report conflicts, exits with an error on problems
+    Check(FileArgs),
+    /// Generate a TypeScript declaration file declaring the auto imports as globals
+    Dts(GenerateArgs),
+    /// Generate an ESLint globals JSON file
+    Eslint(GenerateArgs),
+    /// Generate a Biome config snippet with `javascript.globals`
+    Biome(GenerateArgs),
+    /// Transform a file and print the result
+    Transform {
+        /// Plugin config JSON file
+        #[arg(short, long)]
+        config: PathBuf,
+        /// TypeScript or JavaScript file to transform
+        file: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct FileArgs {
+    /// Plugin config JSON file
+    #[arg(short, long)]
+    config: PathBuf,
+    /// Apply the `include` / `exclude` globs for this file
+    #[arg(short, long)]
+    file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Plugin config JSON file
+    #[arg(short, long)]
+    config: PathBuf,
+    /// Write to this file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let (args, generate): (_, fn(&PluginConfig) -> String) = match cli.command {
+        Command::List(args) => return list(&args),
+        Command::Check(args) => return check(&args),
+        Command::Transform { config, file } => return transform(&config, &file),
+        Command::Dts(args) => (args, generate_dts),
+        Command::Eslint(args) => (args, generate_eslint_globals),
+        Command::Biome(args) => (args, generate_biome_globals),
+    };
+
+    let cached = parse_config(&args.config)?;
+    write_output(args.output.as_ref(), &generate(&cached.config))
+}
+
+/// Parse a config like the plugin does, printing its diagnostics as warnings
+fn parse_config(path: &Path) -> Result<CachedConfig, String> {
+    let cached = CachedConfig::parse(&read_file(path)?);
+    for diagnostic in &cached.diagnostics {
+        eprintln!("warning: invalid config at {}", diagnostic);
+    }
+    if cached.strict && !cached.diagnostics.is_empty() {
+        return Err("invalid config in strict mode".to_string());
+    }
+    if let Some(err) = &cached.parse_error {
+        eprintln!(
+            "warning: failed to parse config, skipping invalid entries: {}",
+            err
+        );
+    }
+    Ok(cached)
+}
+
+fn list(args: &FileArgs) -> Result<(), String> {
+    let cached = CachedConfig::parse(&read_file(&args.config)?);
+    if let Some(err) = &cached.parse_error {
+        eprintln!(
+            "warning: failed to parse config, skipping invalid entries: {}",
+            err
+        );
+    }
+
+    let filename = args.file.as_deref().map(glob_filename);
+    for import in cached.normalized(filename.as_deref()).imports() {
+        println!("{}", import_statement(&import));
+    }
+    Ok(())
+}
+
+fn check(args: &FileArgs) -> Result<(), String> {
+    let cached = CachedConfig::parse(&read_file(&args.config)?);
+    let mut errors = 0;
+
+    for diagnostic in &cached.diagnostics {
+        eprintln!("error: invalid config at {}", diagnostic);
+        errors += 1;
+    }
+    if let Some(err) = &cached.parse_error {
+        eprintln!("error: failed to parse config: {}", err);
+        errors += 1;
+    }
+
+    let filename = args.file.as_deref().map(glob_filename);
+    let normalized = cached.normalized(filename.as_deref());
+    for warning in normalized.warnings() {
+        eprintln!("warning: {}", warning);
+    }
+
+    // Like the plugin, `conflicts: "error"` only refuses names tied at the top priority
+    for import in normalized.imports() {
+        if cached.config.conflicts == ConflictPolicy::Error && import.tied.len() > 1 {
+            eprintln!(
+                "error: `{}` is provided by {} with the same priority",
+                import.local,
+                import.tied.join(", ")
+            );
+            errors += 1;
+        } else if !import.shadowed.is_empty() {
+            eprintln!(
+                "warning: `{}` is provided by `{}`, `{}`, `{}` wins",
+                import.local,
+                import.source,
+                import.shadowed.join("`, `"),
+                import.source
+            );
+        }
+    }
+
+    match errors {
+        0 => {
+            println!("config is valid");
+            Ok(())
+        }
+        1 => Err("found 1 problem".to_string()),
+        errors => Err(format!("found {} problems", errors)),
+    }
+}
+
+fn transform(config: &Path, file: &Path) -> Result<(), String> {
+    let cached = parse_config(config)?;
+
+    let syntax = match file.extension().and_then(|ext| ext.to_str()) {
+        Some("ts" | "mts" | "cts") => Syntax::Typescript(TsSyntax::default()),
+        Some("tsx") => Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+    };
+
+    let cm = Arc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Real(file.to_path_buf()).into(), read_file(file)?);
+    let filename = glob_filename(file);
+
+    let (output, diagnostics) = GLOBALS.set(&Default::default(), || {
+        let comments = SingleThreadedComments::default();
+        let mut module = parse_file_as_module(
+            &fm,
+            syntax,
+            EsVersion::latest(),
+            Some(&comments),
+            &mut vec![],
+        )
+        .map_err(|err| format!("cannot parse `{}`: {}", file.display(), err.kind().msg()))?;
+
+        // Resolve scopes like the SWC host does before running plugins
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+        module.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, true));
+
+        let mut visitor =
+            AutoImportVisitor::from_normalized(cached.normalized(Some(&filename)), unresolved_mark)
+                .with_pragmas(Pragmas::from_comments(&comments, &module));
+        module.visit_mut_with(&mut visitor);
+
+        for warning in visitor.warnings() {
+            eprintln!("warning: {}", warning);
+        }
+
+        let mut buf = vec![];
+        let mut emitter = Emitter {
+            cfg: CodegenConfig::default(),
+            cm: cm.clone(),
+            comments: Some(&comments),
+            wr: JsWriter::new(cm.clone(), "\n", &mut buf, None),
+        };
+        emitter
+            .emit_module(&module)
+            .map_err(|err| format!("cannot emit `{}`: {}", file.display(), err))?;
+
+        Ok::<_, String>((
+            String::from_utf8_lossy(&buf).to_string(),
+            visitor
+                .diagnostics()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+        ))
+    })?;
+
+    print!("{}", output);
+    for diagnostic in &diagnostics {
+        eprintln!("error: {}", diagnostic);
+    }
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("`{}` has auto import errors", file.display()))
+    }
+}
+
+/// Render an auto import as the import statement the plugin would insert
+fn import_statement(import: &ResolvedImport) -> String {
+    let keyword = if import.type_only {
+        "import type"
+    } else {
+        "import"
+    };
+    let clause = match import.imported.as_str() {
+        "*" => format!("* as {}", import.local),
+        "default" => import.local.clone(),
+        name if name == import.local => format!("{{ {} }}", name),
+        name => format!("{{ {} as {} }}", name, import.local),
+    };
+
+    let mut statement = format!("{} {} from '{}'", keyword, clause, import.source);
+    if !import.shadowed.is_empty() {
+        statement.push_str(&format!(" // also in '{}'", import.shadowed.join("', '")));
+    }
+    statement
+}
+
+/// Filename the `include` / `exclude` globs are matched against, relative to the
+/// working directory like inside the plugin
+fn glob_filename(path: &Path) -> String {
+    let cwd = std::env::current_dir().ok();
+    relative_filename(
+        &path.to_string_lossy(),
+        cwd.as_deref().and_then(Path::to_str),
+    )
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read `{}`: {}", path.display(), err))
+}
+
+/// Write generated code to a file, or stdout without one
+fn write_output(output: Option<&PathBuf>, content: &str) -> Result<(), String> {
+    match output {
+        Some(path) => std::fs::write(path, content)
+            .map_err(|err| format!("cannot write `{}`: {}", path.display(), err)),
+        None => {
+            print!("{}", content);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import(local: &str, imported: &str, type_only: bool) -> ResolvedImport {
+        ResolvedImport {
+            local: local.to_string(),
+            source: "mod".to_string(),
+            imported: imported.to_string(),
+            type_only,
+            shadowed: Vec::new(),
+            tied: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_import_statement() {
+        assert_eq!(
+            import_statement(&import("useState", "useState", false)),
+            "import { useState } from 'mod'"
+        );
+        assert_eq!(
+            import_statement(&import("useMount", "useEffect", false)),
+            "import { useEffect as useMount } from 'mod'"
+        );
+        assert_eq!(
+            import_statement(&import("axios", "default", false)),
+            "import axios from 'mod'"
+        );
+        assert_eq!(
+            import_statement(&import("_", "*", false)),
+            "import * as _ from 'mod'"
+        );
+        assert_eq!(
+            import_statement(&import("FC", "FC", true)),
+            "import type { FC } from 'mod'"
+        );
+
+        let mut shadowed = import("Button", "Button", false);
+        shadowed.shadowed = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            import_statement(&shadowed),
+            "import { Button } from 'mod' // also in 'a', 'b'"
+        );
+    }
+}
@@ -0,0 +1,721 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use swc_core::atoms::Atom;
+
+use crate::config::{
+    Arrayable, ConflictPolicy, DirectiveFilter, ImportConfig, ImportItem, ImportSource,
+    InlinePreset, PluginConfig, PresetImport,
+};
+use crate::filter::matches_file;
+use crate::presets::{get_preset_imports, get_preset_type_imports, CLIENT_ONLY_IMPORTS};
+use crate::registry::PresetRegistry;
+use crate::validation::ConfigDiagnostic;
+
+/// Import map: source -> [(name, alias)]
+type ImportMap = HashMap<String, Vec<(String, Option<String>)>>;
+
+/// Import map with the conflict rank of each import: source -> [(name, alias, rank)]
+type RankedImportMap = HashMap<String, Vec<(String, Option<String>, ExportRank)>>;
+
+/// Conflict rank of an export: (priority, index of the top-level entry providing it)
+type ExportRank = (i32, usize);
+
+/// Directive filter key for modules without a directive
+pub(crate) const DEFAULT_DIRECTIVE: &str = "default";
+
+/// Positions an auto imported export can satisfy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum ExportKind {
+    /// Value and type positions
+    Value,
+    /// Type positions only, always imported as a type
+    Type,
+}
+
+/// An export that can be auto imported, indexed by its local name
+#[derive(Debug, Clone)]
+pub(crate) struct IndexedExport {
+    pub(crate) source: String,
+    pub(crate) name: String,
+    pub(crate) alias: Option<String>,
+    pub(crate) kind: ExportKind,
+    pub(crate) rank: ExportRank,
+}
+
+/// The export a local name is auto imported from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedImport {
+    /// Local name in the module
+    pub local: String,
+    /// Module specifier
+    pub source: String,
+    /// Exported name, `default` or `*` for a namespace
+    pub imported: String,
+    /// Only satisfies type positions
+    pub type_only: bool,
+    /// Other sources providing the same local name that lose to `source`
+    pub shadowed: Vec<String>,
+    /// Exports tied for the win, `source` first, formatted as `` `source` `` or
+    /// `` `source` (`name`) ``. `conflicts: "error"` refuses the name when there
+    /// are several
+    pub tied: Vec<String>,
+}
+
+/// Plugin configuration resolved for a file: presets expanded, glob filters
+/// applied and the exports indexed by local name
+///
+/// Only the filename's glob matches are baked in, so one instance serves every
+/// file with the same matches.
+#[derive(Debug)]
+pub struct NormalizedConfig {
+    /// Local name -> exports providing it, value exports first, then by rank
+    pub(crate) index: HashMap<Atom, Vec<IndexedExport>>,
+    /// How to resolve a local name that several sources provide
+    pub(crate) conflicts: ConflictPolicy,
+    /// Append to existing import declarations from the same source
+    pub(crate) merge_existing: bool,
+    /// Allow/deny lists keyed by module directive, "default" for modules without one
+    pub(crate) directive_filters: HashMap<String, DirectiveFilter>,
+    /// Problems found while normalizing the import maps
+    pub(crate) warnings: Vec<ConfigDiagnostic>,
+    /// Whether the warnings were reported already
+    reported: AtomicBool,
+    /// Enable debug logging
+    pub(crate) debug: bool,
+}
+
+impl NormalizedConfig {
+    /// Normalize a configuration for a file, applying the `include` / `exclude` globs
+    ///
+    /// `filename` should be relative to the working directory. Without a filename
+    /// every glob filter passes.
+    pub fn new(config: &PluginConfig, registry: &PresetRegistry, filename: Option<&str>) -> Self {
+        let debug = config.debug;
+        let merge_existing = config.merge_existing;
+        let mut import_map = HashMap::new();
+        let mut type_import_map = HashMap::new();
+        // JSON path of each top-level entry, indexed by the entry's rank. Legacy
+        // `presets` continue after `imports`
+        let mut entry_paths = Vec::new();
+
+        // Server action and cached modules can't use client-only APIs. Modules without
+        // a directive may be shared with client code, they are only filtered when
+        // `directives.default` is configured
+        let mut directive_filters = config.directives.clone();
+        if config.rsc {
+            for directive in ["use server", "use cache"] {
+                directive_filters
+                    .entry(directive.to_string())
+                    .or_insert_with(|| DirectiveFilter {
+                        allow: None,
+                        deny: CLIENT_ONLY_IMPORTS
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect(),
+                    });
+            }
+        }
+
+        // Excluded files get no auto imports at all
+        let included = filename
+            .is_none_or(|filename| matches_file(&config.include, &config.exclude, filename));
+
+        // Process imports (Option<Arrayable<ImportConfig>>)
+        if let Some(imports) = config.imports.as_ref().filter(|_| included) {
+            // Convert Arrayable to Vec for iteration
+            let imports_vec = match imports {
+                Arrayable::Single(item) => std::slice::from_ref(item),
+                Arrayable::Array(items) => items.as_slice(),
+            };
+
+            for (i, import_config) in imports_vec.iter().enumerate() {
+                let rank = (import_config.priority(), entry_paths.len());
+                entry_paths.push(match imports {
+                    Arrayable::Single(_) => "$.imports".to_string(),
+                    Arrayable::Array(_) => format!("$.imports[{}]", i),
+                });
+                Self::process_entry(
+                    &mut import_map,
+                    &mut type_import_map,
+                    rank,
+                    registry,
+                    filename,
+                    import_config.clone(),
+                );
+            }
+        }
+
+        // Legacy `presets` field: same as listing the names in `imports`
+        if let Some(presets) = config.presets.as_ref().filter(|_| included) {
+            let presets_vec = match presets {
+                Arrayable::Single(preset) => std::slice::from_ref(preset),
+                Arrayable::Array(presets) => presets.as_slice(),
+            };
+
+            for (i, preset) in presets_vec.iter().enumerate() {
+                let rank = (0, entry_paths.len());
+                entry_paths.push(match presets {
+                    Arrayable::Single(_) => "$.presets".to_string(),
+                    Arrayable::Array(_) => format!("$.presets[{}]", i),
+                });
+                Self::process_entry(
+                    &mut import_map,
+                    &mut type_import_map,
+                    rank,
+                    registry,
+                    filename,
+                    ImportConfig::PresetName(preset.clone()),
+                );
+            }
+        }
+
+        let mut warnings = Vec::new();
+        Self::normalize_import_map(&mut import_map, &entry_paths, &mut warnings);
+        Self::normalize_import_map(&mut type_import_map, &entry_paths, &mut warnings);
+
+        let ignore: HashSet<String> = config.ignore.iter().cloned().collect();
+        let index = Self::build_index(import_map, type_import_map, &ignore);
+
+        Self {
+            index,
+            conflicts: config.conflicts,
+            merge_existing,
+            directive_filters,
+            warnings,
+            reported: AtomicBool::new(false),
+            debug,
+        }
+    }
+
+    /// Problems found while normalizing the import maps, located by the entry
+    /// that introduced them
+    pub fn warnings(&self) -> &[ConfigDiagnostic] {
+        &self.warnings
+    }
+
+    /// Mark the warnings as reported, false if they were reported before
+    ///
+    /// Files sharing this instance would repeat the same warnings otherwise.
+    pub fn mark_reported(&self) -> bool {
+        !self.reported.swap(true, Ordering::Relaxed)
+    }
+
+    /// Every auto import, sorted by local name
+    ///
+    /// A name with value and type exports has an import for each, the value first.
+    pub fn imports(&self) -> Vec<ResolvedImport> {
+        let mut imports = Vec::new();
+        for (local_name, exports) in &self.index {
+            for kind in [ExportKind::Value, ExportKind::Type] {
+                let exports: Vec<_> = exports
+                    .iter()
+                    .filter(|export| export.kind == kind)
+                    .collect();
+                let Some((export, others)) = exports.split_first() else {
+                    continue;
+                };
+
+                let mut shadowed: Vec<String> = Vec::new();
+                for other in others {
+                    if other.source != export.source && !shadowed.contains(&other.source) {
+                        shadowed.push(other.source.clone());
+                    }
+                }
+                let tied = tied_exports(exports.iter().copied());
+
+                imports.push(ResolvedImport {
+                    local: local_name.to_string(),
+                    source: export.source.clone(),
+                    imported: export.name.clone(),
+                    type_only: kind == ExportKind::Type,
+                    shadowed,
+                    tied,
+                });
+            }
+        }
+        imports.sort_by(|a, b| (&a.local, a.type_only).cmp(&(&b.local, b.type_only)));
+        imports
+    }
+
+    /// The winning value export and the winning type export of each local name,
+    /// sorted by local name
+    ///
+    /// Names that `conflicts: "error"` refuses to import are left out.
+    pub(crate) fn resolved_exports(&self) -> Vec<(&Atom, &IndexedExport)> {
+        let mut resolved = Vec::new();
+        for (local_name, exports) in &self.index {
+            for kind in [ExportKind::Value, ExportKind::Type] {
+                let exports: Vec<_> = exports
+                    .iter()
+                    .filter(|export| export.kind == kind)
+                    .collect();
+                let Some(export) = exports.first().copied() else {
+                    continue;
+                };
+                if self.conflicts == ConflictPolicy::Error && tied_exports(exports).len() > 1 {
+                    continue;
+                }
+                resolved.push((local_name, export));
+            }
+        }
+        resolved.sort_by(|a, b| (a.0, a.1.kind).cmp(&(b.0, b.1.kind)));
+        resolved
+    }
+
+    /// Deduplicate the entries of an import map and drop the ones that can't be imported
+    ///
+    /// Presets and ImportsMap entries are merged, so the same (name, alias) pair can
+    /// appear several times. An export listed under different local names is kept
+    /// but reported, as it is usually a mistake.
+    fn normalize_import_map(
+        import_map: &mut RankedImportMap,
+        entry_paths: &[String],
+        warnings: &mut Vec<ConfigDiagnostic>,
+    ) {
+        let mut warn = |(_, order): &ExportRank, message: String| {
+            warnings.push(ConfigDiagnostic {
+                path: entry_paths[*order].clone(),
+                message,
+            })
+        };
+
+        let mut sources: Vec<_> = import_map.iter_mut().collect();
+        sources.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (source, imports) in sources {
+            // A pair listed by several entries keeps the best rank among them
+            let mut ranks: HashMap<(String, Option<String>), ExportRank> = HashMap::new();
+            for (name, alias, rank) in imports.iter() {
+                ranks
+                    .entry((name.clone(), alias.clone()))
+                    .and_modify(|best| {
+                        if (Reverse(rank.0), rank.1) < (Reverse(best.0), best.1) {
+                            *best = *rank;
+                        }
+                    })
+                    .or_insert(*rank);
+            }
+
+            let mut seen = HashSet::new();
+            imports.retain(|(name, alias, rank)| {
+                let local_name = alias.as_ref().unwrap_or(name);
+                if (name == "default" || name == "*") && alias.is_none() {
+                    warn(rank, format!("`{}` from `{}` needs an alias", name, source));
+                    false
+                } else if !is_identifier(local_name)
+                    || (name != "default" && name != "*" && !is_identifier(name))
+                {
+                    warn(
+                        rank,
+                        format!("`{}` from `{}` is not a valid identifier", name, source),
+                    );
+                    false
+                } else {
+                    seen.insert((name.clone(), alias.clone()))
+                }
+            });
+
+            // Local names per export in config order, reported at the entry that
+            // added the second name
+            let mut locals: Vec<(&String, Vec<(&String, &ExportRank)>)> = Vec::new();
+            for (name, alias, rank) in imports.iter() {
+                let local_name = alias.as_ref().unwrap_or(name);
+                match locals.iter_mut().find(|(export, _)| *export == name) {
+                    Some((_, names)) => names.push((local_name, rank)),
+                    None => locals.push((name, vec![(local_name, rank)])),
+                }
+            }
+            for (name, names) in locals.into_iter().filter(|(_, names)| names.len() > 1) {
+                let rank = names[1].1;
+                let names: Vec<_> = names
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect();
+                warn(
+                    rank,
+                    format!(
+                        "`{}` from `{}` is imported under several names: {}",
+                        name,
+                        source,
+                        names.join(", ")
+                    ),
+                );
+            }
+
+            // Exports per local name in config order, the reverse mistake: one local
+            // name for several exports, only the first of which can win
+            let mut exports: Vec<(&String, Vec<(&String, &ExportRank)>)> = Vec::new();
+            for (name, alias, rank) in imports.iter() {
+                let local_name = alias.as_ref().unwrap_or(name);
+                match exports.iter_mut().find(|(local, _)| *local == local_name) {
+                    Some((_, names)) if names.iter().all(|(other, _)| *other != name) => {
+                        names.push((name, rank))
+                    }
+                    Some(_) => {}
+                    None => exports.push((local_name, vec![(name, rank)])),
+                }
+            }
+            for (local_name, names) in exports.into_iter().filter(|(_, names)| names.len() > 1) {
+                let rank = names[1].1;
+                let names: Vec<_> = names
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect();
+                warn(
+                    rank,
+                    format!(
+                        "`{}` from `{}` is an alias of several exports: {}",
+                        local_name,
+                        source,
+                        names.join(", ")
+                    ),
+                );
+            }
+
+            for (name, alias, rank) in imports.iter_mut() {
+                *rank = ranks[&(name.clone(), alias.clone())];
+            }
+        }
+    }
+
+    /// Index the normalized import maps by local name
+    ///
+    /// Each name's exports are sorted so the first one wins: value exports before
+    /// type exports, then the highest priority, then the earliest config entry.
+    fn build_index(
+        import_map: RankedImportMap,
+        type_import_map: RankedImportMap,
+        ignore: &HashSet<String>,
+    ) -> HashMap<Atom, Vec<IndexedExport>> {
+        let mut index: HashMap<Atom, Vec<IndexedExport>> = HashMap::new();
+
+        let entries = import_map
+            .into_iter()
+            .map(|(source, imports)| (source, imports, ExportKind::Value))
+            .chain(
+                type_import_map
+                    .into_iter()
+                    .map(|(source, imports)| (source, imports, ExportKind::Type)),
+            );
+
+        for (source, imports, kind) in entries {
+            for (name, alias, rank) in imports {
+                let local_name = alias.as_ref().unwrap_or(&name);
+                if ignore.contains(local_name) {
+                    continue;
+                }
+
+                index
+                    .entry(Atom::from(local_name.as_str()))
+                    .or_default()
+                    .push(IndexedExport {
+                        source: source.clone(),
+                        name,
+                        alias,
+                        kind,
+                        rank,
+                    });
+            }
+        }
+
+        for exports in index.values_mut() {
+            exports.sort_by(|a, b| {
+                (a.kind, Reverse(a.rank.0), a.rank.1, &a.source).cmp(&(
+                    b.kind,
+                    Reverse(b.rank.0),
+                    b.rank.1,
+                    &b.source,
+                ))
+            });
+        }
+
+        index
+    }
+
+    /// Process a top-level config entry, ranking every import it provides
+    fn process_entry(
+        import_map: &mut RankedImportMap,
+        type_import_map: &mut RankedImportMap,
+        rank: ExportRank,
+        registry: &PresetRegistry,
+        filename: Option<&str>,
+        import_config: ImportConfig,
+    ) {
+        let mut entry_imports = HashMap::new();
+        let mut entry_type_imports = HashMap::new();
+        Self::process_import_config(
+            &mut entry_imports,
+            &mut entry_type_imports,
+            registry,
+            &mut Vec::new(),
+            filename,
+            import_config,
+        );
+
+        let ranked = |imports: Vec<(String, Option<String>)>| {
+            imports
+                .into_iter()
+                .map(move |(name, alias)| (name, alias, rank))
+        };
+        for (source, imports) in entry_imports {
+            import_map
+                .entry(source)
+                .or_default()
+                .extend(ranked(imports));
+        }
+        for (source, imports) in entry_type_imports {
+            type_import_map
+                .entry(source)
+                .or_default()
+                .extend(ranked(imports));
+        }
+    }
+
+    /// Process a single ImportConfig and add to import_map (or type_import_map for types)
+    ///
+    /// `resolving` holds the custom presets currently being expanded, so a preset
+    /// that references itself falls back to the built-in of the same name.
+    fn process_import_config(
+        import_map: &mut ImportMap,
+        type_import_map: &mut ImportMap,
+        registry: &PresetRegistry,
+        resolving: &mut Vec<String>,
+        filename: Option<&str>,
+        import_config: ImportConfig,
+    ) {
+        match import_config {
+            // PresetName: a `customPresets` entry, or a built-in like "react", "vue",
+            // "react-dom". Inside its own definition a custom preset's name refers
+            // to the built-in
+            ImportConfig::PresetName(preset) => {
+                if let Some(definition) = registry
+                    .get(&preset)
+                    .filter(|_| !resolving.contains(&preset))
+                {
+                    resolving.push(preset);
+                    for import_config in definition {
+                        Self::process_import_config(
+                            import_map,
+                            type_import_map,
+                            registry,
+                            resolving,
+                            filename,
+                            import_config.clone(),
+                        );
+                    }
+                    resolving.pop();
+                } else {
+                    let preset_imports = get_preset_imports(&preset);
+                    for (source, imports) in preset_imports {
+                        import_map.entry(source).or_default().extend(imports);
+                    }
+                    let preset_type_imports = get_preset_type_imports(&preset);
+                    for (source, imports) in preset_type_imports {
+                        type_import_map.entry(source).or_default().extend(imports);
+                    }
+                }
+            }
+            // InlinePreset: { from: "react", imports: ["useState", "useEffect"] }
+            ImportConfig::InlinePreset(inline_preset) => {
+                Self::process_inline_preset(
+                    import_map,
+                    type_import_map,
+                    inline_preset,
+                    false,
+                    filename,
+                );
+            }
+            // ScopedPreset: { preset: "vitest", include: ["**/*.test.ts"] }
+            ImportConfig::ScopedPreset(scoped) => {
+                if filename
+                    .is_none_or(|filename| matches_file(&scoped.include, &scoped.exclude, filename))
+                {
+                    Self::process_import_config(
+                        import_map,
+                        type_import_map,
+                        registry,
+                        resolving,
+                        filename,
+                        ImportConfig::PresetName(scoped.preset),
+                    );
+                }
+            }
+            // Explicit form (legacy): [{ name: "ref", from: "vue" }, ...]
+            ImportConfig::Explicit(items) => {
+                for item in items {
+                    import_map
+                        .entry(item.from.clone())
+                        .or_default()
+                        .push((item.name.clone(), item.alias.clone()));
+                }
+            }
+            // ImportsMap: { "package": ["export1", "export2"] }
+            ImportConfig::ImportsMap(map) => {
+                for (source, import_source) in map {
+                    let import_list: Vec<(String, Option<String>)> = match import_source {
+                        ImportSource::Simple(names) => {
+                            names.iter().map(|name| (name.clone(), None)).collect()
+                        }
+                        ImportSource::WithAlias(items) => items
+                            .iter()
+                            .map(|item| match item {
+                                ImportItem::Simple(name) => (name.clone(), None),
+                                ImportItem::Aliased([name, alias]) => {
+                                    (name.clone(), Some(alias.clone()))
+                                }
+                            })
+                            .collect(),
+                    };
+
+                    import_map
+                        .entry(source.clone())
+                        .or_default()
+                        .extend(import_list);
+                }
+            }
+        }
+    }
+
+    /// Process an InlinePreset and add to import_map
+    ///
+    /// Presets with `"type": true` go to type_import_map instead. Nested presets
+    /// inherit the flag of their parent unless they set it themselves. Presets whose
+    /// `include` / `exclude` globs reject the file are skipped with their nested presets.
+    fn process_inline_preset(
+        import_map: &mut ImportMap,
+        type_import_map: &mut ImportMap,
+        inline_preset: InlinePreset,
+        parent_type_only: bool,
+        filename: Option<&str>,
+    ) {
+        if filename.is_some_and(|filename| {
+            !matches_file(&inline_preset.include, &inline_preset.exclude, filename)
+        }) {
+            return;
+        }
+
+        let source = inline_preset.from;
+        let type_only = inline_preset.type_only.unwrap_or(parent_type_only);
+
+        for preset_import in inline_preset.imports {
+            Self::process_preset_import(
+                import_map,
+                type_import_map,
+                preset_import,
+                &source,
+                type_only,
+                filename,
+            );
+        }
+    }
+
+    /// Process a PresetImport and add to import_map (or type_import_map for types)
+    fn process_preset_import(
+        import_map: &mut ImportMap,
+        type_import_map: &mut ImportMap,
+        preset_import: PresetImport,
+        default_source: &str,
+        type_only: bool,
+        filename: Option<&str>,
+    ) {
+        let target_map = if type_only {
+            &mut *type_import_map
+        } else {
+            &mut *import_map
+        };
+
+        match preset_import {
+            // Simple string: "useState"
+            PresetImport::Simple(name) => {
+                target_map
+                    .entry(default_source.to_string())
+                    .or_default()
+                    .push((name, None));
+            }
+            // Tuple: ["useState", "useSignal"] or ["useState", "useSignal", "react"]
+            PresetImport::Tuple(parts) => {
+                let (name, alias, source) = match parts.len() {
+                    1 => (parts[0].clone(), None, default_source.to_string()),
+                    2 => (
+                        parts[0].clone(),
+                        Some(parts[1].clone()),
+                        default_source.to_string(),
+                    ),
+                    3 => (parts[0].clone(), Some(parts[1].clone()), parts[2].clone()),
+                    _ => return, // Invalid tuple length
+                };
+
+                target_map.entry(source).or_default().push((name, alias));
+            }
+            // Object: { name: "useState", as?: "useSignal" }
+            PresetImport::Object { name, alias } => {
+                target_map
+                    .entry(default_source.to_string())
+                    .or_default()
+                    .push((name, alias));
+            }
+            // Nested InlinePreset
+            PresetImport::Nested(nested) => {
+                Self::process_inline_preset(
+                    import_map,
+                    type_import_map,
+                    *nested,
+                    type_only,
+                    filename,
+                );
+            }
+        }
+    }
+}
+
+/// Exports of the winning export's kind with the winning priority, the winner
+/// first, formatted as `` `source` `` or `` `source` (`name`) `` when a source
+/// provides the name through several exports
+///
+/// `exports` must be sorted like the index. A strictly higher priority wins even
+/// under `conflicts: "error"`, only exports tied with the winner are ambiguous.
+pub(crate) fn tied_exports<'a>(
+    exports: impl IntoIterator<Item = &'a IndexedExport>,
+) -> Vec<String> {
+    let mut exports = exports.into_iter();
+    let Some(winner) = exports.next() else {
+        return Vec::new();
+    };
+
+    let mut tied = vec![winner];
+    for export in exports {
+        if export.kind == winner.kind
+            && export.rank.0 == winner.rank.0
+            && !tied
+                .iter()
+                .any(|other| other.source == export.source && other.name == export.name)
+        {
+            tied.push(export);
+        }
+    }
+
+    tied.iter()
+        .map(|export| {
+            if tied
+                .iter()
+                .filter(|other| other.source == export.source)
+                .count()
+                > 1
+            {
+                format!("`{}` (`{}`)", export.source, export.name)
+            } else {
+                format!("`{}`", export.source)
+            }
+        })
+        .collect()
+}
+
+/// Whether `name` can be used as a JavaScript binding
+pub(crate) fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+use swc_core::common::{comments::Comments, Spanned};
+use swc_core::ecma::ast::{Expr, ExprStmt, Lit, Module, ModuleItem, Stmt};
+
+/// In-file pragmas that disable or restrict auto imports
+///
+/// - `// @auto-import-disable` - no auto imports in this file
+/// - `// @auto-import-ignore useState, ref` - never auto import these local names
+/// - `// @auto-import-only react, vue` - only auto import from these sources
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pragmas {
+    /// Skip the file entirely
+    pub disabled: bool,
+    /// Local names that are never auto imported
+    pub ignore: HashSet<String>,
+    /// Sources auto imports are restricted to, `None` allows all
+    pub only: Option<HashSet<String>>,
+}
+
+impl Pragmas {
+    /// Read pragmas from the comments at the top of the module
+    ///
+    /// Only comments before the first statement count, including the ones around
+    /// leading directives such as `"use client"`.
+    pub fn from_comments(comments: &impl Comments, module: &Module) -> Self {
+        let mut positions = vec![module.span.lo];
+        for item in &module.body {
+            positions.push(item.span_lo());
+            if !is_directive(item) {
+                break;
+            }
+        }
+        positions.dedup();
+
+        let mut pragmas = Self::default();
+        for comment in positions
+            .into_iter()
+            .filter_map(|pos| comments.get_leading(pos))
+            .flatten()
+        {
+            pragmas.parse_comment(&comment.text);
+        }
+
+        pragmas
+    }
+
+    /// Parse the pragmas in a single comment's text
+    pub fn parse_comment(&mut self, text: &str) {
+        for line in text.lines() {
+            let line = line.trim().trim_start_matches('*').trim();
+            let (pragma, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+
+            let names = rest
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|name| !name.is_empty())
+                .map(ToString::to_string);
+
+            match pragma {
+                "@auto-import-disable" => self.disabled = true,
+                "@auto-import-ignore" => self.ignore.extend(names),
+                "@auto-import-only" => self.only.get_or_insert_with(HashSet::new).extend(names),
+                _ => {}
+            }
+        }
+    }
+
+    /// Whether `local_name` may be auto imported from `source`
+    pub fn permits(&self, local_name: &str, source: &str) -> bool {
+        !self.disabled
+            && !self.ignore.contains(local_name)
+            && self.only.as_ref().is_none_or(|only| only.contains(source))
+    }
+}
+
+fn is_directive(item: &ModuleItem) -> bool {
+    match item {
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+            matches!(**expr, Expr::Lit(Lit::Str(_)))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(comments: &[&str]) -> Pragmas {
+        let mut pragmas = Pragmas::default();
+        for comment in comments {
+            pragmas.parse_comment(comment);
+        }
+        pragmas
+    }
+
+    #[test]
+    fn test_parse_pragmas() {
+        let pragmas = parse(&[
+            " @auto-import-ignore useState, ref",
+            " @auto-import-only react",
+            "*\n * @auto-import-ignore computed\n * @auto-import-only vue ",
+        ]);
+        assert!(!pragmas.disabled);
+        assert!(pragmas.permits("useEffect", "react"));
+        assert!(pragmas.permits("watch", "vue"));
+        assert!(!pragmas.permits("useState", "react"));
+        assert!(!pragmas.permits("computed", "vue"));
+        assert!(!pragmas.permits("useRouter", "next/navigation"));
+    }
+
+    #[test]
+    fn test_disable_pragma() {
+        assert!(parse(&[" @auto-import-disable"]).disabled);
+        assert!(!parse(&[" @auto-import-disabled"]).disabled);
+        assert!(!parse(&[" see @auto-import-disable"]).disabled);
+        assert!(!parse(&[" @auto-import-disable"]).permits("useState", "react"));
+    }
+}
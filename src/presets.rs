@@ -1,5 +1,44 @@
 use std::collections::HashMap;
 
+mod vueuse;
+
+use vueuse::VUEUSE_CORE;
+
+/// Convert a table of export names into (name, alias) tuples without aliases
+fn named_imports(names: &[&str]) -> Vec<(String, Option<String>)> {
+    names.iter().map(|name| (name.to_string(), None)).collect()
+}
+
+/// React and Next.js APIs that only work in client components, denied in server
+/// modules when `rsc` is enabled
+pub const CLIENT_ONLY_IMPORTS: &[&str] = &[
+    // react
+    "createContext",
+    "useActionState",
+    "useContext",
+    "useDeferredValue",
+    "useEffect",
+    "useEffectEvent",
+    "useImperativeHandle",
+    "useInsertionEffect",
+    "useLayoutEffect",
+    "useOptimistic",
+    "useReducer",
+    "useRef",
+    "useState",
+    "useSyncExternalStore",
+    "useTransition",
+    // react-dom
+    "useFormStatus",
+    // next/navigation
+    "useParams",
+    "usePathname",
+    "useRouter",
+    "useSearchParams",
+    "useSelectedLayoutSegment",
+    "useSelectedLayoutSegments",
+];
+
 /// Preset configuration - returns mapping from package name to import list
 /// Each import is a (name, alias) tuple, where alias is None means no alias
 pub fn get_preset_imports(preset: &str) -> HashMap<String, Vec<(String, Option<String>)>> {
@@ -9,56 +48,627 @@ pub fn get_preset_imports(preset: &str) -> HashMap<String, Vec<(String, Option<S
         "react" => {
             map.insert(
                 "react".to_string(),
-                vec![
+                named_imports(&[
                     // Common React API
-                    ("useState".to_string(), None),
-                    ("useCallback".to_string(), None),
-                    ("useMemo".to_string(), None),
-                    ("useEffect".to_string(), None),
-                    ("useRef".to_string(), None),
-                    ("useContext".to_string(), None),
-                    ("useReducer".to_string(), None),
-                    ("useImperativeHandle".to_string(), None),
-                    ("useDebugValue".to_string(), None),
-                    ("useDeferredValue".to_string(), None),
-                    ("useLayoutEffect".to_string(), None),
-                    ("useTransition".to_string(), None),
-                    ("startTransition".to_string(), None),
-                    ("useSyncExternalStore".to_string(), None),
-                    ("useInsertionEffect".to_string(), None),
-                    ("useId".to_string(), None),
-                    ("lazy".to_string(), None),
-                    ("memo".to_string(), None),
-                    ("createRef".to_string(), None),
-                    ("forwardRef".to_string(), None),
+                    "useState",
+                    "useCallback",
+                    "useMemo",
+                    "useEffect",
+                    "useRef",
+                    "useContext",
+                    "useReducer",
+                    "useImperativeHandle",
+                    "useDebugValue",
+                    "useDeferredValue",
+                    "useLayoutEffect",
+                    "useTransition",
+                    "startTransition",
+                    "useSyncExternalStore",
+                    "useInsertionEffect",
+                    "useId",
+                    "lazy",
+                    "memo",
+                    "createRef",
+                    "forwardRef",
                     // Additional React API
-                    ("cache".to_string(), None),
-                    ("cacheSignal".to_string(), None),
-                    ("createContext".to_string(), None),
-                    ("use".to_string(), None),
-                    ("useOptimistic".to_string(), None),
-                    ("useEffectEvent".to_string(), None),
-                    ("useActionState".to_string(), None),
-                    ("Fragment".to_string(), None),
-                    ("Suspense".to_string(), None),
-                    ("Activity".to_string(), None),
-                ],
+                    "cache",
+                    "cacheSignal",
+                    "createContext",
+                    "use",
+                    "useOptimistic",
+                    "useEffectEvent",
+                    "useActionState",
+                    "Fragment",
+                    "Suspense",
+                    "Activity",
+                ]),
+            );
+        }
+        "react-router" => {
+            map.insert(
+                "react-router".to_string(),
+                named_imports(&[
+                    // Hooks
+                    "useHref",
+                    "useInRouterContext",
+                    "useLinkClickHandler",
+                    "useLocation",
+                    "useMatch",
+                    "useNavigate",
+                    "useNavigationType",
+                    "useOutlet",
+                    "useOutletContext",
+                    "useParams",
+                    "useResolvedPath",
+                    "useRoutes",
+                    "useSearchParams",
+                    // Data router hooks
+                    "useActionData",
+                    "useBlocker",
+                    "useFetcher",
+                    "useLoaderData",
+                    "useMatches",
+                    "useNavigation",
+                    "useRevalidator",
+                    "useRouteError",
+                    "useSubmit",
+                    // Components
+                    "Link",
+                    "NavLink",
+                    "Navigate",
+                    "Outlet",
+                    "Route",
+                    "Routes",
+                ]),
+            );
+        }
+        "@tanstack/react-query" => {
+            map.insert(
+                "@tanstack/react-query".to_string(),
+                named_imports(&[
+                    // Hooks
+                    "useInfiniteQuery",
+                    "useIsFetching",
+                    "useIsMutating",
+                    "useMutation",
+                    "useMutationState",
+                    "usePrefetchQuery",
+                    "useQueries",
+                    "useQuery",
+                    "useQueryClient",
+                    "useSuspenseInfiniteQuery",
+                    "useSuspenseQueries",
+                    "useSuspenseQuery",
+                    // Client and helpers
+                    "HydrationBoundary",
+                    "QueryClient",
+                    "QueryClientProvider",
+                    "infiniteQueryOptions",
+                    "keepPreviousData",
+                    "queryOptions",
+                    "skipToken",
+                ]),
+            );
+        }
+        "zustand" => {
+            map.insert(
+                "zustand".to_string(),
+                named_imports(&["create", "createStore", "useStore"]),
+            );
+        }
+        "jotai" => {
+            map.insert(
+                "jotai".to_string(),
+                named_imports(&[
+                    "atom",
+                    "getDefaultStore",
+                    "useAtom",
+                    "useAtomValue",
+                    "useSetAtom",
+                ]),
+            );
+        }
+        "vue" => {
+            map.insert(
+                "vue".to_string(),
+                named_imports(&[
+                    // Lifecycle
+                    "onActivated",
+                    "onBeforeMount",
+                    "onBeforeUnmount",
+                    "onBeforeUpdate",
+                    "onDeactivated",
+                    "onErrorCaptured",
+                    "onMounted",
+                    "onRenderTracked",
+                    "onRenderTriggered",
+                    "onScopeDispose",
+                    "onServerPrefetch",
+                    "onUnmounted",
+                    "onUpdated",
+                    // Reactivity
+                    "computed",
+                    "customRef",
+                    "isProxy",
+                    "isReactive",
+                    "isReadonly",
+                    "isRef",
+                    "markRaw",
+                    "reactive",
+                    "readonly",
+                    "ref",
+                    "shallowReactive",
+                    "shallowReadonly",
+                    "shallowRef",
+                    "toRaw",
+                    "toRef",
+                    "toRefs",
+                    "toValue",
+                    "triggerRef",
+                    "unref",
+                    // Watchers and effect scopes
+                    "watch",
+                    "watchEffect",
+                    "watchPostEffect",
+                    "watchSyncEffect",
+                    "onWatcherCleanup",
+                    "effectScope",
+                    "getCurrentScope",
+                    // Component
+                    "defineAsyncComponent",
+                    "defineComponent",
+                    "getCurrentInstance",
+                    "h",
+                    "inject",
+                    "hasInjectionContext",
+                    "nextTick",
+                    "provide",
+                    "useAttrs",
+                    "useCssModule",
+                    "useId",
+                    "useModel",
+                    "useSlots",
+                    "useTemplateRef",
+                    // Application
+                    "createApp",
+                ]),
             );
         }
         "react-dom" => {
             map.insert(
                 "react-dom".to_string(),
-                vec![
-                    ("useFormStatus".to_string(), None),
-                    ("createPortal".to_string(), None),
-                    ("flushSync".to_string(), None),
-                    ("preconnect".to_string(), None),
-                    ("prefetchDNS".to_string(), None),
-                    ("preinit".to_string(), None),
-                    ("preinitModule".to_string(), None),
-                    ("preload".to_string(), None),
-                    ("preloadModule".to_string(), None),
-                ],
+                named_imports(&[
+                    "useFormStatus",
+                    "createPortal",
+                    "flushSync",
+                    "preconnect",
+                    "prefetchDNS",
+                    "preinit",
+                    "preinitModule",
+                    "preload",
+                    "preloadModule",
+                ]),
+            );
+        }
+        "vue-router" => {
+            map.insert(
+                "vue-router".to_string(),
+                named_imports(&[
+                    "useLink",
+                    "useRoute",
+                    "useRouter",
+                    "onBeforeRouteLeave",
+                    "onBeforeRouteUpdate",
+                ]),
+            );
+        }
+        "pinia" => {
+            map.insert(
+                "pinia".to_string(),
+                named_imports(&[
+                    "acceptHMRUpdate",
+                    "createPinia",
+                    "defineStore",
+                    "getActivePinia",
+                    "mapActions",
+                    "mapGetters",
+                    "mapState",
+                    "mapStores",
+                    "mapWritableState",
+                    "setActivePinia",
+                    "setMapStoreSuffix",
+                    "storeToRefs",
+                ]),
+            );
+        }
+        "@vueuse/core" => {
+            map.insert("@vueuse/core".to_string(), named_imports(VUEUSE_CORE));
+        }
+        // Next.js App Router APIs live in subpath modules
+        "next" => {
+            // Components are default exports. `Image` would shadow the DOM's
+            // `Image` constructor, so it is imported as `NextImage`
+            map.insert(
+                "next/image".to_string(),
+                vec![("default".to_string(), Some("NextImage".to_string()))],
+            );
+            map.insert(
+                "next/link".to_string(),
+                vec![("default".to_string(), Some("Link".to_string()))],
+            );
+            map.insert(
+                "next/script".to_string(),
+                vec![("default".to_string(), Some("Script".to_string()))],
+            );
+            map.insert(
+                "next/dynamic".to_string(),
+                vec![("default".to_string(), Some("dynamic".to_string()))],
+            );
+            map.insert(
+                "next/navigation".to_string(),
+                named_imports(&[
+                    "useParams",
+                    "usePathname",
+                    "useRouter",
+                    "useSearchParams",
+                    "useSelectedLayoutSegment",
+                    "useSelectedLayoutSegments",
+                    "notFound",
+                    "permanentRedirect",
+                    "redirect",
+                ]),
+            );
+            map.insert(
+                "next/headers".to_string(),
+                named_imports(&["cookies", "draftMode", "headers"]),
+            );
+            map.insert(
+                "next/server".to_string(),
+                named_imports(&[
+                    "NextRequest",
+                    "NextResponse",
+                    "after",
+                    "connection",
+                    "userAgent",
+                ]),
+            );
+            map.insert(
+                "next/cache".to_string(),
+                named_imports(&["revalidatePath", "revalidateTag"]),
+            );
+        }
+        "solid-js" => {
+            map.insert(
+                "solid-js".to_string(),
+                named_imports(&[
+                    "batch",
+                    "children",
+                    "createComputed",
+                    "createContext",
+                    "createDeferred",
+                    "createEffect",
+                    "createMemo",
+                    "createRenderEffect",
+                    "createResource",
+                    "createRoot",
+                    "createSelector",
+                    "createSignal",
+                    "lazy",
+                    "mergeProps",
+                    "on",
+                    "onCleanup",
+                    "onError",
+                    "onMount",
+                    "splitProps",
+                    "startTransition",
+                    "untrack",
+                    "useContext",
+                    "useTransition",
+                    "ErrorBoundary",
+                    "For",
+                    "Index",
+                    "Match",
+                    "Show",
+                    "Suspense",
+                    "SuspenseList",
+                    "Switch",
+                ]),
+            );
+            map.insert(
+                "solid-js/store".to_string(),
+                named_imports(&[
+                    "createMutable",
+                    "createStore",
+                    "produce",
+                    "reconcile",
+                    "unwrap",
+                ]),
+            );
+            map.insert(
+                "solid-js/web".to_string(),
+                named_imports(&["Dynamic", "Portal", "isServer"]),
+            );
+        }
+        "preact" => {
+            map.insert(
+                "preact".to_string(),
+                named_imports(&[
+                    "Component",
+                    "Fragment",
+                    "cloneElement",
+                    "createContext",
+                    "createRef",
+                    "h",
+                    "hydrate",
+                    "isValidElement",
+                    "render",
+                    "toChildArray",
+                ]),
+            );
+            map.insert(
+                "preact/hooks".to_string(),
+                named_imports(&[
+                    "useCallback",
+                    "useContext",
+                    "useDebugValue",
+                    "useEffect",
+                    "useErrorBoundary",
+                    "useId",
+                    "useImperativeHandle",
+                    "useLayoutEffect",
+                    "useMemo",
+                    "useReducer",
+                    "useRef",
+                    "useState",
+                ]),
+            );
+        }
+        // Svelte 5 runtime APIs for `.svelte.js` / `.svelte.ts` modules
+        "svelte" => {
+            map.insert(
+                "svelte".to_string(),
+                named_imports(&[
+                    "createRawSnippet",
+                    "flushSync",
+                    "getAllContexts",
+                    "getContext",
+                    "hasContext",
+                    "hydrate",
+                    "mount",
+                    "onDestroy",
+                    "onMount",
+                    "setContext",
+                    "tick",
+                    "unmount",
+                    "untrack",
+                ]),
+            );
+            map.insert(
+                "svelte/store".to_string(),
+                named_imports(&[
+                    "derived",
+                    "fromStore",
+                    "get",
+                    "readable",
+                    "readonly",
+                    "toStore",
+                    "writable",
+                ]),
+            );
+        }
+        "vitest" => {
+            map.insert(
+                "vitest".to_string(),
+                named_imports(&[
+                    // Suites and tests
+                    "bench",
+                    "describe",
+                    "it",
+                    "suite",
+                    "test",
+                    // Hooks
+                    "afterAll",
+                    "afterEach",
+                    "beforeAll",
+                    "beforeEach",
+                    "onTestFailed",
+                    "onTestFinished",
+                    // Assertions and mocks
+                    "assert",
+                    "assertType",
+                    "chai",
+                    "expect",
+                    "expectTypeOf",
+                    "vi",
+                ]),
+            );
+        }
+        // Jest globals live in `@jest/globals`
+        "jest" => {
+            map.insert(
+                "@jest/globals".to_string(),
+                named_imports(&[
+                    // Suites and tests
+                    "describe",
+                    "fdescribe",
+                    "fit",
+                    "it",
+                    "test",
+                    "xdescribe",
+                    "xit",
+                    "xtest",
+                    // Hooks
+                    "afterAll",
+                    "afterEach",
+                    "beforeAll",
+                    "beforeEach",
+                    // Assertions and mocks
+                    "expect",
+                    "jest",
+                ]),
+            );
+        }
+        _ => {}
+    }
+
+    map
+}
+
+/// Type preset configuration - returns mapping from package name to type exports
+///
+/// These are only imported for identifiers used in type positions, and always
+/// emitted as type-only imports. Classes that are also values are listed here
+/// too, value exports alone only satisfy `typeof` queries.
+pub fn get_preset_type_imports(preset: &str) -> HashMap<String, Vec<(String, Option<String>)>> {
+    let mut map = HashMap::new();
+
+    match preset {
+        "react" => {
+            map.insert(
+                "react".to_string(),
+                named_imports(&[
+                    "ChangeEvent",
+                    "ComponentProps",
+                    "ComponentPropsWithRef",
+                    "ComponentPropsWithoutRef",
+                    "ComponentType",
+                    "CSSProperties",
+                    "Dispatch",
+                    "ElementRef",
+                    "FC",
+                    "FormEvent",
+                    "PropsWithChildren",
+                    "ReactElement",
+                    "ReactNode",
+                    "RefObject",
+                    "SetStateAction",
+                ]),
+            );
+        }
+        "react-router" => {
+            map.insert(
+                "react-router".to_string(),
+                named_imports(&[
+                    "ActionFunctionArgs",
+                    "LoaderFunctionArgs",
+                    "NavigateFunction",
+                    "RouteObject",
+                ]),
+            );
+        }
+        "@tanstack/react-query" => {
+            map.insert(
+                "@tanstack/react-query".to_string(),
+                named_imports(&[
+                    "QueryClient",
+                    "QueryKey",
+                    "UseMutationOptions",
+                    "UseMutationResult",
+                    "UseQueryOptions",
+                    "UseQueryResult",
+                ]),
+            );
+        }
+        "zustand" => {
+            map.insert(
+                "zustand".to_string(),
+                named_imports(&["StateCreator", "StoreApi"]),
+            );
+        }
+        "jotai" => {
+            map.insert(
+                "jotai".to_string(),
+                named_imports(&["Atom", "PrimitiveAtom", "WritableAtom"]),
+            );
+        }
+        "vue" => {
+            map.insert(
+                "vue".to_string(),
+                named_imports(&[
+                    "ComputedRef",
+                    "Component",
+                    "ComponentPublicInstance",
+                    "DirectiveBinding",
+                    "ExtractDefaultPropTypes",
+                    "ExtractPropTypes",
+                    "ExtractPublicPropTypes",
+                    "InjectionKey",
+                    "MaybeRef",
+                    "MaybeRefOrGetter",
+                    "PropType",
+                    "Ref",
+                    "VNode",
+                    "WritableComputedRef",
+                ]),
+            );
+        }
+        "vue-router" => {
+            map.insert(
+                "vue-router".to_string(),
+                named_imports(&[
+                    "NavigationGuard",
+                    "RouteLocationNormalized",
+                    "RouteLocationNormalizedLoaded",
+                    "RouteLocationRaw",
+                    "RouteRecordRaw",
+                    "Router",
+                ]),
+            );
+        }
+        "pinia" => {
+            map.insert(
+                "pinia".to_string(),
+                named_imports(&["Pinia", "StoreGeneric"]),
+            );
+        }
+        "next" => {
+            map.insert(
+                "next".to_string(),
+                named_imports(&["Metadata", "NextConfig", "Viewport"]),
+            );
+            map.insert(
+                "next/server".to_string(),
+                named_imports(&["NextRequest", "NextResponse"]),
+            );
+        }
+        "solid-js" => {
+            map.insert(
+                "solid-js".to_string(),
+                named_imports(&[
+                    "Accessor",
+                    "Component",
+                    "ParentComponent",
+                    "ParentProps",
+                    "Setter",
+                    "Signal",
+                ]),
+            );
+        }
+        "preact" => {
+            map.insert(
+                "preact".to_string(),
+                named_imports(&[
+                    "Component",
+                    "ComponentChildren",
+                    "FunctionComponent",
+                    "VNode",
+                ]),
+            );
+        }
+        "svelte" => {
+            map.insert(
+                "svelte".to_string(),
+                named_imports(&["Component", "Snippet"]),
+            );
+            map.insert(
+                "svelte/store".to_string(),
+                named_imports(&["Readable", "Writable"]),
+            );
+        }
+        "vitest" => {
+            map.insert(
+                "vitest".to_string(),
+                named_imports(&["Mock", "MockInstance", "Mocked", "TestContext"]),
             );
         }
         _ => {}
@@ -92,4 +702,186 @@ mod tests {
         assert!(react_dom_imports.contains(&("useFormStatus".to_string(), None)));
         assert!(react_dom_imports.contains(&("createPortal".to_string(), None)));
     }
+
+    #[test]
+    fn test_react_type_preset() {
+        let imports = get_preset_type_imports("react");
+        let react_types = imports.get("react").unwrap();
+        assert!(react_types.contains(&("FC".to_string(), None)));
+        assert!(react_types.contains(&("ReactNode".to_string(), None)));
+        assert!(get_preset_type_imports("react-dom").is_empty());
+    }
+
+    #[test]
+    fn test_vue_preset() {
+        let imports = get_preset_imports("vue");
+        assert!(imports.contains_key("vue"));
+        let vue_imports = imports.get("vue").unwrap();
+        assert!(vue_imports.len() >= 50);
+        assert!(vue_imports.contains(&("ref".to_string(), None)));
+        assert!(vue_imports.contains(&("computed".to_string(), None)));
+        assert!(vue_imports.contains(&("onMounted".to_string(), None)));
+        assert!(vue_imports.contains(&("defineComponent".to_string(), None)));
+        assert!(vue_imports.contains(&("h".to_string(), None)));
+        assert!(vue_imports.contains(&("nextTick".to_string(), None)));
+        assert!(vue_imports.contains(&("watchEffect".to_string(), None)));
+    }
+
+    #[test]
+    fn test_vue_type_preset() {
+        let imports = get_preset_type_imports("vue");
+        let vue_types = imports.get("vue").unwrap();
+        assert!(vue_types.contains(&("Ref".to_string(), None)));
+        assert!(vue_types.contains(&("ComputedRef".to_string(), None)));
+    }
+
+    #[test]
+    fn test_vue_router_preset() {
+        let imports = get_preset_imports("vue-router");
+        let router_imports = imports.get("vue-router").unwrap();
+        assert_eq!(router_imports.len(), 5);
+        assert!(router_imports.contains(&("useRoute".to_string(), None)));
+        assert!(router_imports.contains(&("useRouter".to_string(), None)));
+        assert!(router_imports.contains(&("onBeforeRouteLeave".to_string(), None)));
+    }
+
+    #[test]
+    fn test_pinia_preset() {
+        let imports = get_preset_imports("pinia");
+        let pinia_imports = imports.get("pinia").unwrap();
+        assert!(pinia_imports.contains(&("defineStore".to_string(), None)));
+        assert!(pinia_imports.contains(&("storeToRefs".to_string(), None)));
+    }
+
+    #[test]
+    fn test_vueuse_preset() {
+        let imports = get_preset_imports("@vueuse/core");
+        let vueuse_imports = imports.get("@vueuse/core").unwrap();
+        assert_eq!(vueuse_imports.len(), VUEUSE_CORE.len());
+        assert!(vueuse_imports.len() >= 200);
+        assert!(vueuse_imports.contains(&("useMouse".to_string(), None)));
+        assert!(vueuse_imports.contains(&("useLocalStorage".to_string(), None)));
+        assert!(!vueuse_imports.contains(&("toRef".to_string(), None)));
+    }
+
+    #[test]
+    fn test_vueuse_table_is_sorted_and_unique() {
+        assert!(VUEUSE_CORE.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_react_router_preset() {
+        let imports = get_preset_imports("react-router");
+        let router_imports = imports.get("react-router").unwrap();
+        assert!(router_imports.contains(&("useNavigate".to_string(), None)));
+        assert!(router_imports.contains(&("useParams".to_string(), None)));
+        assert!(router_imports.contains(&("useLoaderData".to_string(), None)));
+        assert!(router_imports.contains(&("Link".to_string(), None)));
+    }
+
+    #[test]
+    fn test_tanstack_react_query_preset() {
+        let imports = get_preset_imports("@tanstack/react-query");
+        let query_imports = imports.get("@tanstack/react-query").unwrap();
+        assert!(query_imports.contains(&("useQuery".to_string(), None)));
+        assert!(query_imports.contains(&("useMutation".to_string(), None)));
+        assert!(query_imports.contains(&("useQueryClient".to_string(), None)));
+    }
+
+    #[test]
+    fn test_zustand_preset() {
+        let imports = get_preset_imports("zustand");
+        let zustand_imports = imports.get("zustand").unwrap();
+        assert_eq!(zustand_imports.len(), 3);
+        assert!(zustand_imports.contains(&("create".to_string(), None)));
+    }
+
+    #[test]
+    fn test_jotai_preset() {
+        let imports = get_preset_imports("jotai");
+        let jotai_imports = imports.get("jotai").unwrap();
+        assert_eq!(jotai_imports.len(), 5);
+        assert!(jotai_imports.contains(&("atom".to_string(), None)));
+        assert!(jotai_imports.contains(&("useAtom".to_string(), None)));
+    }
+
+    #[test]
+    fn test_vitest_preset() {
+        let imports = get_preset_imports("vitest");
+        let vitest_imports = imports.get("vitest").unwrap();
+        assert!(vitest_imports.contains(&("describe".to_string(), None)));
+        assert!(vitest_imports.contains(&("it".to_string(), None)));
+        assert!(vitest_imports.contains(&("expect".to_string(), None)));
+        assert!(vitest_imports.contains(&("vi".to_string(), None)));
+        assert!(vitest_imports.contains(&("beforeEach".to_string(), None)));
+    }
+
+    #[test]
+    fn test_jest_preset() {
+        let imports = get_preset_imports("jest");
+        assert!(!imports.contains_key("jest"));
+        let jest_imports = imports.get("@jest/globals").unwrap();
+        assert!(jest_imports.contains(&("describe".to_string(), None)));
+        assert!(jest_imports.contains(&("jest".to_string(), None)));
+        assert!(jest_imports.contains(&("beforeEach".to_string(), None)));
+    }
+
+    #[test]
+    fn test_next_preset() {
+        let imports = get_preset_imports("next");
+        assert!(!imports.contains_key("next"));
+        assert_eq!(
+            imports.get("next/image").unwrap(),
+            &vec![("default".to_string(), Some("NextImage".to_string()))]
+        );
+        assert_eq!(
+            imports.get("next/link").unwrap(),
+            &vec![("default".to_string(), Some("Link".to_string()))]
+        );
+        let navigation = imports.get("next/navigation").unwrap();
+        assert!(navigation.contains(&("useRouter".to_string(), None)));
+        assert!(navigation.contains(&("usePathname".to_string(), None)));
+        assert!(navigation.contains(&("notFound".to_string(), None)));
+        assert!(navigation.contains(&("redirect".to_string(), None)));
+        let headers = imports.get("next/headers").unwrap();
+        assert!(headers.contains(&("cookies".to_string(), None)));
+        assert!(headers.contains(&("headers".to_string(), None)));
+        let server = imports.get("next/server").unwrap();
+        assert!(server.contains(&("NextResponse".to_string(), None)));
+    }
+
+    #[test]
+    fn test_solid_preset() {
+        let imports = get_preset_imports("solid-js");
+        let solid_imports = imports.get("solid-js").unwrap();
+        assert!(solid_imports.contains(&("createSignal".to_string(), None)));
+        assert!(solid_imports.contains(&("createEffect".to_string(), None)));
+        assert!(solid_imports.contains(&("onMount".to_string(), None)));
+        assert!(solid_imports.contains(&("Show".to_string(), None)));
+        assert!(solid_imports.contains(&("For".to_string(), None)));
+        let store_imports = imports.get("solid-js/store").unwrap();
+        assert!(store_imports.contains(&("createStore".to_string(), None)));
+    }
+
+    #[test]
+    fn test_preact_preset() {
+        let imports = get_preset_imports("preact");
+        let preact_imports = imports.get("preact").unwrap();
+        assert!(preact_imports.contains(&("h".to_string(), None)));
+        assert!(preact_imports.contains(&("Fragment".to_string(), None)));
+        let hooks_imports = imports.get("preact/hooks").unwrap();
+        assert!(hooks_imports.contains(&("useState".to_string(), None)));
+        assert!(!preact_imports.contains(&("useState".to_string(), None)));
+    }
+
+    #[test]
+    fn test_svelte_preset() {
+        let imports = get_preset_imports("svelte");
+        let svelte_imports = imports.get("svelte").unwrap();
+        assert!(svelte_imports.contains(&("onMount".to_string(), None)));
+        assert!(svelte_imports.contains(&("tick".to_string(), None)));
+        let store_imports = imports.get("svelte/store").unwrap();
+        assert!(store_imports.contains(&("writable".to_string(), None)));
+        assert!(store_imports.contains(&("derived".to_string(), None)));
+    }
 }
@@ -0,0 +1,232 @@
+/// Exports of `@vueuse/core`
+///
+/// Kept as a sorted table so it can be diffed against upstream releases.
+/// `toRef`, `toRefs` and `toValue` are re-exported by VueUse but left out
+/// because they clash with the `vue` preset.
+pub const VUEUSE_CORE: &[&str] = &[
+    "asyncComputed",
+    "autoResetRef",
+    "computedAsync",
+    "computedEager",
+    "computedInject",
+    "computedWithControl",
+    "controlledComputed",
+    "controlledRef",
+    "createEventHook",
+    "createGlobalState",
+    "createInjectionState",
+    "createReactiveFn",
+    "createReusableTemplate",
+    "createSharedComposable",
+    "createTemplatePromise",
+    "createUnrefFn",
+    "debouncedRef",
+    "debouncedWatch",
+    "eagerComputed",
+    "extendRef",
+    "ignorableWatch",
+    "injectLocal",
+    "isDefined",
+    "logicAnd",
+    "logicNot",
+    "logicOr",
+    "makeDestructurable",
+    "onClickOutside",
+    "onKeyDown",
+    "onKeyPressed",
+    "onKeyStroke",
+    "onKeyUp",
+    "onLongPress",
+    "onStartTyping",
+    "pausableWatch",
+    "provideLocal",
+    "reactify",
+    "reactifyObject",
+    "reactiveComputed",
+    "reactiveOmit",
+    "reactivePick",
+    "refAutoReset",
+    "refDebounced",
+    "refDefault",
+    "refThrottled",
+    "refWithControl",
+    "syncRef",
+    "syncRefs",
+    "templateRef",
+    "throttledRef",
+    "throttledWatch",
+    "toReactive",
+    "tryOnBeforeMount",
+    "tryOnBeforeUnmount",
+    "tryOnMounted",
+    "tryOnScopeDispose",
+    "tryOnUnmounted",
+    "unrefElement",
+    "until",
+    "useActiveElement",
+    "useAnimate",
+    "useArrayDifference",
+    "useArrayEvery",
+    "useArrayFilter",
+    "useArrayFind",
+    "useArrayFindIndex",
+    "useArrayFindLast",
+    "useArrayIncludes",
+    "useArrayJoin",
+    "useArrayMap",
+    "useArrayReduce",
+    "useArraySome",
+    "useArrayUnique",
+    "useAsyncQueue",
+    "useAsyncState",
+    "useBase64",
+    "useBattery",
+    "useBluetooth",
+    "useBreakpoints",
+    "useBroadcastChannel",
+    "useBrowserLocation",
+    "useCached",
+    "useClipboard",
+    "useClipboardItems",
+    "useCloned",
+    "useColorMode",
+    "useConfirmDialog",
+    "useCounter",
+    "useCssVar",
+    "useCurrentElement",
+    "useCycleList",
+    "useDark",
+    "useDateFormat",
+    "useDebounce",
+    "useDebounceFn",
+    "useDebouncedRefHistory",
+    "useDeviceMotion",
+    "useDeviceOrientation",
+    "useDevicePixelRatio",
+    "useDevicesList",
+    "useDisplayMedia",
+    "useDocumentVisibility",
+    "useDraggable",
+    "useDropZone",
+    "useElementBounding",
+    "useElementByPoint",
+    "useElementHover",
+    "useElementSize",
+    "useElementVisibility",
+    "useEventBus",
+    "useEventListener",
+    "useEventSource",
+    "useEyeDropper",
+    "useFavicon",
+    "useFetch",
+    "useFileDialog",
+    "useFileSystemAccess",
+    "useFocus",
+    "useFocusWithin",
+    "useFps",
+    "useFullscreen",
+    "useGamepad",
+    "useGeolocation",
+    "useIdle",
+    "useImage",
+    "useInfiniteScroll",
+    "useIntersectionObserver",
+    "useInterval",
+    "useIntervalFn",
+    "useKeyModifier",
+    "useLastChanged",
+    "useLocalStorage",
+    "useMagicKeys",
+    "useManualRefHistory",
+    "useMediaControls",
+    "useMediaQuery",
+    "useMemoize",
+    "useMemory",
+    "useMounted",
+    "useMouse",
+    "useMouseInElement",
+    "useMousePressed",
+    "useMutationObserver",
+    "useNavigatorLanguage",
+    "useNetwork",
+    "useNow",
+    "useObjectUrl",
+    "useOffsetPagination",
+    "useOnline",
+    "usePageLeave",
+    "useParallax",
+    "useParentElement",
+    "usePerformanceObserver",
+    "usePermission",
+    "usePointer",
+    "usePointerLock",
+    "usePointerSwipe",
+    "usePreferredColorScheme",
+    "usePreferredContrast",
+    "usePreferredDark",
+    "usePreferredLanguages",
+    "usePreferredReducedMotion",
+    "usePrevious",
+    "useRafFn",
+    "useRefHistory",
+    "useResizeObserver",
+    "useScreenOrientation",
+    "useScreenSafeArea",
+    "useScriptTag",
+    "useScroll",
+    "useScrollLock",
+    "useSessionStorage",
+    "useShare",
+    "useSorted",
+    "useSpeechRecognition",
+    "useSpeechSynthesis",
+    "useStepper",
+    "useStorage",
+    "useStorageAsync",
+    "useStyleTag",
+    "useSupported",
+    "useSwipe",
+    "useTemplateRefsList",
+    "useTextDirection",
+    "useTextSelection",
+    "useTextareaAutosize",
+    "useThrottle",
+    "useThrottleFn",
+    "useThrottledRefHistory",
+    "useTimeAgo",
+    "useTimeout",
+    "useTimeoutFn",
+    "useTimeoutPoll",
+    "useTimestamp",
+    "useTitle",
+    "useToNumber",
+    "useToString",
+    "useToggle",
+    "useTransition",
+    "useUrlSearchParams",
+    "useUserMedia",
+    "useVModel",
+    "useVModels",
+    "useVibrate",
+    "useVirtualList",
+    "useWakeLock",
+    "useWebNotification",
+    "useWebSocket",
+    "useWebWorker",
+    "useWebWorkerFn",
+    "useWindowFocus",
+    "useWindowScroll",
+    "useWindowSize",
+    "watchArray",
+    "watchAtMost",
+    "watchDebounced",
+    "watchDeep",
+    "watchIgnorable",
+    "watchImmediate",
+    "watchOnce",
+    "watchPausable",
+    "watchThrottled",
+    "watchTriggerable",
+    "watchWithFilter",
+    "whenever",
+];
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Arrayable, ImportConfig, PresetDefinition};
+use crate::presets::get_preset_imports;
+
+/// Presets that can be referenced by name: the built-ins plus the ones defined
+/// in the `customPresets` config field
+///
+/// A custom preset shadows a built-in of the same name. Inside its own
+/// definition the name refers to the built-in, so `{ "react": ["react", {...}] }`
+/// extends the React preset.
+#[derive(Debug, Clone, Default)]
+pub struct PresetRegistry {
+    /// Custom preset name -> import configs
+    custom: HashMap<String, Vec<ImportConfig>>,
+}
+
+impl PresetRegistry {
+    /// Build the registry from the `customPresets` config field
+    ///
+    /// Preset files that cannot be read or parsed are skipped, `validate_config`
+    /// reports them.
+    pub fn new(definitions: &HashMap<String, PresetDefinition>) -> Self {
+        let mut custom = HashMap::new();
+
+        for (name, definition) in definitions {
+            let imports = match definition {
+                PresetDefinition::Inline(imports) => Some(imports.clone()),
+                PresetDefinition::File(path) => read_preset_file(path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str(&content).ok()),
+            };
+
+            if let Some(imports) = imports {
+                let imports = match imports {
+                    Arrayable::Single(item) => vec![item],
+                    Arrayable::Array(items) => items,
+                };
+                custom.insert(name.clone(), imports);
+            }
+        }
+
+        Self { custom }
+    }
+
+    /// Definition of a custom preset
+    pub fn get(&self, name: &str) -> Option<&[ImportConfig]> {
+        self.custom.get(name).map(Vec::as_slice)
+    }
+
+    /// Import configs of every custom preset
+    pub(crate) fn definitions(&self) -> impl Iterator<Item = &ImportConfig> {
+        self.custom.values().flatten()
+    }
+
+    /// Whether `name` is a custom or built-in preset
+    pub fn contains(&self, name: &str) -> bool {
+        self.custom.contains_key(name) || !get_preset_imports(name).is_empty()
+    }
+}
+
+/// Read a preset definition file
+///
+/// Relative paths resolve against the project directory, see `resolve_preset_path`.
+pub(crate) fn read_preset_file(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(resolve_preset_path(path))
+        .map_err(|err| format!("cannot read preset file `{}`: {}", path, err))
+}
+
+/// Resolve a preset file path against the project directory
+///
+/// Natively that is the working directory of the process. Inside the Wasm sandbox
+/// SWC mounts the project directory at `/cwd`, so the same relative path works
+/// in the plugin and the CLI.
+fn resolve_preset_path(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    if cfg!(target_arch = "wasm32") && path.is_relative() {
+        Path::new("/cwd").join(path)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(config: &str) -> PresetRegistry {
+        let definitions = serde_json::from_str(config).unwrap();
+        PresetRegistry::new(&definitions)
+    }
+
+    #[test]
+    fn test_inline_definitions() {
+        let registry = registry(
+            r#"{
+                "design-system": { "from": "@acme/ui", "imports": ["Button", "Card"] },
+                "app": ["react", { "@/utils": ["cn"] }]
+            }"#,
+        );
+        assert_eq!(registry.get("design-system").unwrap().len(), 1);
+        assert_eq!(registry.get("app").unwrap().len(), 2);
+        assert!(registry.contains("design-system"));
+        assert!(registry.contains("react"));
+        assert!(!registry.contains("reakt"));
+    }
+
+    #[test]
+    fn test_file_definitions() {
+        // Unique per process and test, so parallel runs don't share the file
+        let path = std::env::temp_dir().join(format!(
+            "swc-plugin-auto-import-registry-{}-file-definitions.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, r#"[{ "from": "@acme/ui", "imports": ["Button"] }]"#).unwrap();
+
+        let config = serde_json::json!({
+            "design-system": path.to_string_lossy(),
+            "missing": "does/not/exist.json",
+        });
+        let registry = registry(&config.to_string());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(registry.get("design-system").unwrap().len(), 1);
+        assert!(registry.get("missing").is_none());
+    }
+
+    #[test]
+    fn test_resolve_preset_path() {
+        let expected = if cfg!(target_arch = "wasm32") {
+            "/cwd/presets/ui.json"
+        } else {
+            "presets/ui.json"
+        };
+        assert_eq!(resolve_preset_path("presets/ui.json"), Path::new(expected));
+        assert_eq!(
+            resolve_preset_path("/abs/ui.json"),
+            Path::new("/abs/ui.json")
+        );
+    }
+
+    #[test]
+    fn test_builtins() {
+        let registry = registry("{}");
+        assert!(registry.get("vue").is_none());
+        assert!(registry.contains("vue"));
+    }
+}
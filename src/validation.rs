@@ -0,0 +1,619 @@
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::presets::get_preset_imports;
+use crate::registry::read_preset_file;
+
+/// A problem found in the plugin configuration, located by a JSON path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDiagnostic {
+    /// JSON path of the offending entry, e.g. `$.imports[1]["@vueuse/core"][0]`
+    pub path: String,
+    /// Human readable description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Validate a raw plugin configuration string
+///
+/// Serde silently ignores unknown keys and untagged enums collapse every shape
+/// error into a single message, so the configuration is checked against the
+/// expected shape before it is deserialized.
+pub fn validate_config(config_str: &str) -> Vec<ConfigDiagnostic> {
+    let mut validator = Validator::default();
+
+    match serde_json::from_str::<Value>(config_str) {
+        Ok(value) => validator.validate_root(&value),
+        Err(err) => validator.report("$", format!("invalid JSON: {}", err)),
+    }
+
+    validator.diagnostics
+}
+
+#[derive(Default)]
+struct Validator {
+    diagnostics: Vec<ConfigDiagnostic>,
+    /// Names defined in `customPresets`
+    custom_presets: HashSet<String>,
+}
+
+impl Validator {
+    fn report(&mut self, path: &str, message: impl Into<String>) {
+        self.diagnostics.push(ConfigDiagnostic {
+            path: path.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn validate_root(&mut self, value: &Value) {
+        let Some(object) = self.expect_object(value, "$") else {
+            return;
+        };
+
+        self.check_keys(
+            object,
+            "$",
+            &[
+                "imports",
+                "customPresets",
+                "presets",
+                "mergeExisting",
+                "ignore",
+                "conflicts",
+                "include",
+                "exclude",
+                "rsc",
+                "directives",
+                "debug",
+                "strict",
+            ],
+        );
+
+        // Registry names must be known before any PresetName is checked
+        if let Some(Value::Object(definitions)) = object.get("customPresets") {
+            self.custom_presets = definitions.keys().cloned().collect();
+        }
+
+        if let Some(imports) = object.get("imports") {
+            self.validate_imports(imports, "$.imports");
+        }
+
+        if let Some(definitions) = object.get("customPresets") {
+            let path = "$.customPresets";
+            if let Some(definitions) = self.expect_object(definitions, path) {
+                for (name, definition) in definitions {
+                    self.validate_preset_definition(definition, &key_path(path, name));
+                }
+            }
+        }
+
+        if let Some(presets) = object.get("presets") {
+            let path = "$.presets";
+            match presets {
+                Value::String(preset) => self.validate_preset_name(preset, path),
+                Value::Array(items) => {
+                    for (index, item) in items.iter().enumerate() {
+                        let item_path = index_path(path, index);
+                        match item {
+                            Value::String(preset) => self.validate_preset_name(preset, &item_path),
+                            _ => self.report(
+                                &item_path,
+                                format!("expected a preset name, found {}", describe(item)),
+                            ),
+                        }
+                    }
+                }
+                _ => self.report(
+                    path,
+                    format!(
+                        "expected a preset name or an array of preset names, found {}",
+                        describe(presets)
+                    ),
+                ),
+            }
+        }
+
+        self.expect_string_array_field(object, "$", "ignore");
+        match object.get("conflicts") {
+            Some(Value::String(policy)) if ["priority", "error"].contains(&policy.as_str()) => {}
+            Some(_) => self.report("$.conflicts", "expected one of: \"priority\", \"error\""),
+            None => {}
+        }
+        self.expect_string_array_field(object, "$", "include");
+        self.expect_string_array_field(object, "$", "exclude");
+
+        if let Some(directives) = object.get("directives") {
+            let path = "$.directives";
+            if let Some(directives) = self.expect_object(directives, path) {
+                for (directive, filter) in directives {
+                    self.validate_directive_filter(filter, &key_path(path, directive));
+                }
+            }
+        }
+
+        for key in ["mergeExisting", "rsc", "debug", "strict"] {
+            if let Some(value) = object.get(key) {
+                if !value.is_boolean() {
+                    self.report(&key_path("$", key), "expected a boolean");
+                }
+            }
+        }
+    }
+
+    /// Arrayable<ImportConfig>
+    fn validate_imports(&mut self, value: &Value, path: &str) {
+        match value {
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.validate_import_config(item, &index_path(path, index));
+                }
+            }
+            _ => self.validate_import_config(value, path),
+        }
+    }
+
+    /// File path | Arrayable<ImportConfig>
+    fn validate_preset_definition(&mut self, value: &Value, path: &str) {
+        match value {
+            Value::String(file) => {
+                let content = match read_preset_file(file) {
+                    Ok(content) => content,
+                    Err(message) => return self.report(path, message),
+                };
+                match serde_json::from_str::<Value>(&content) {
+                    Ok(definition @ (Value::Array(_) | Value::Object(_))) => {
+                        self.validate_imports(&definition, path)
+                    }
+                    Ok(other) => self.report(
+                        path,
+                        format!(
+                            "expected preset file `{}` to hold an array or an object, found {}",
+                            file,
+                            describe(&other)
+                        ),
+                    ),
+                    Err(err) => self.report(
+                        path,
+                        format!("invalid JSON in preset file `{}`: {}", file, err),
+                    ),
+                }
+            }
+            Value::Array(_) | Value::Object(_) => self.validate_imports(value, path),
+            _ => self.report(
+                path,
+                format!(
+                    "expected a preset definition or a path to a preset file, found {}",
+                    describe(value)
+                ),
+            ),
+        }
+    }
+
+    /// { allow?: string[], deny?: string[] }
+    fn validate_directive_filter(&mut self, value: &Value, path: &str) {
+        let Some(object) = self.expect_object(value, path) else {
+            return;
+        };
+
+        self.check_keys(object, path, &["allow", "deny"]);
+        self.expect_string_array_field(object, path, "allow");
+        self.expect_string_array_field(object, path, "deny");
+    }
+
+    /// { preset: PresetName, include?: string[], exclude?: string[] }
+    fn validate_scoped_preset(&mut self, object: &Map<String, Value>, path: &str) {
+        self.check_keys(object, path, &["preset", "include", "exclude", "priority"]);
+        self.expect_integer_field(object, path, "priority");
+        match object.get("preset") {
+            Some(Value::String(preset)) => {
+                self.validate_preset_name(preset, &key_path(path, "preset"))
+            }
+            Some(other) => self.report(
+                &key_path(path, "preset"),
+                format!("expected a preset name, found {}", describe(other)),
+            ),
+            None => {}
+        }
+        self.expect_string_array_field(object, path, "include");
+        self.expect_string_array_field(object, path, "exclude");
+    }
+
+    /// ImportsMap | PresetName | InlinePreset | ExplicitImport[]
+    fn validate_import_config(&mut self, value: &Value, path: &str) {
+        match value {
+            Value::String(preset) => self.validate_preset_name(preset, path),
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.validate_explicit_import(item, &index_path(path, index));
+                }
+            }
+            Value::Object(object) if object.contains_key("from") => {
+                self.validate_inline_preset(object, path)
+            }
+            Value::Object(object) if object.contains_key("preset") => {
+                self.validate_scoped_preset(object, path)
+            }
+            Value::Object(object) => self.validate_imports_map(object, path),
+            _ => self.report(
+                path,
+                format!(
+                    "expected a preset name, an imports map, an inline preset or an array of explicit imports, found {}",
+                    describe(value)
+                ),
+            ),
+        }
+    }
+
+    fn validate_preset_name(&mut self, preset: &str, path: &str) {
+        if !self.custom_presets.contains(preset) && get_preset_imports(preset).is_empty() {
+            self.report(path, format!("unknown preset `{}`", preset));
+        }
+    }
+
+    /// { name: string, as?: string, from: string }
+    fn validate_explicit_import(&mut self, value: &Value, path: &str) {
+        let Some(object) = self.expect_object(value, path) else {
+            return;
+        };
+
+        self.check_keys(object, path, &["name", "as", "from"]);
+        self.expect_string_field(object, path, "name", true);
+        self.expect_string_field(object, path, "as", false);
+        self.expect_string_field(object, path, "from", true);
+    }
+
+    /// { "package": (string | [string, string])[] }
+    fn validate_imports_map(&mut self, object: &Map<String, Value>, path: &str) {
+        for (source, imports) in object {
+            let source_path = key_path(path, source);
+            let Value::Array(items) = imports else {
+                self.report(
+                    &source_path,
+                    format!("expected an array of imports, found {}", describe(imports)),
+                );
+                continue;
+            };
+
+            for (index, item) in items.iter().enumerate() {
+                let item_path = index_path(&source_path, index);
+                match item {
+                    Value::String(_) => {}
+                    Value::Array(parts)
+                        if parts.len() == 2 && parts.iter().all(Value::is_string) => {}
+                    Value::Array(parts) => self.report(
+                        &item_path,
+                        format!(
+                            "expected a [name, alias] tuple of two strings, found {} element(s)",
+                            parts.len()
+                        ),
+                    ),
+                    _ => self.report(
+                        &item_path,
+                        format!(
+                            "expected a name or a [name, alias] tuple, found {}",
+                            describe(item)
+                        ),
+                    ),
+                }
+            }
+        }
+    }
+
+    /// { from: string, type?: boolean, imports: PresetImport[], include?: string[], exclude?: string[] }
+    fn validate_inline_preset(&mut self, object: &Map<String, Value>, path: &str) {
+        self.check_keys(
+            object,
+            path,
+            &["from", "type", "imports", "include", "exclude", "priority"],
+        );
+        self.expect_integer_field(object, path, "priority");
+        self.expect_string_field(object, path, "from", true);
+        self.expect_string_array_field(object, path, "include");
+        self.expect_string_array_field(object, path, "exclude");
+
+        if let Some(type_only) = object.get("type") {
+            if !type_only.is_boolean() {
+                self.report(&key_path(path, "type"), "expected a boolean");
+            }
+        }
+
+        let imports_path = key_path(path, "imports");
+        match object.get("imports") {
+            Some(Value::Array(items)) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.validate_preset_import(item, &index_path(&imports_path, index));
+                }
+            }
+            Some(other) => self.report(
+                &imports_path,
+                format!("expected an array of imports, found {}", describe(other)),
+            ),
+            None => self.report(path, "missing required key `imports`"),
+        }
+    }
+
+    /// string | [name, as?, from?] | { name, as? } | InlinePreset
+    fn validate_preset_import(&mut self, value: &Value, path: &str) {
+        match value {
+            Value::String(_) => {}
+            Value::Array(parts) => {
+                if parts.is_empty() || parts.len() > 3 {
+                    self.report(
+                        path,
+                        format!(
+                            "expected a [name, as?, from?] tuple of 1 to 3 strings, found {} element(s)",
+                            parts.len()
+                        ),
+                    );
+                } else if !parts.iter().all(Value::is_string) {
+                    self.report(path, "tuple elements must be strings");
+                }
+            }
+            Value::Object(object) if object.contains_key("from") => {
+                self.validate_inline_preset(object, path)
+            }
+            Value::Object(object) => {
+                self.check_keys(object, path, &["name", "as"]);
+                self.expect_string_field(object, path, "name", true);
+                self.expect_string_field(object, path, "as", false);
+            }
+            _ => self.report(
+                path,
+                format!(
+                    "expected a name, a tuple, an object or an inline preset, found {}",
+                    describe(value)
+                ),
+            ),
+        }
+    }
+
+    fn expect_object<'a>(
+        &mut self,
+        value: &'a Value,
+        path: &str,
+    ) -> Option<&'a Map<String, Value>> {
+        match value {
+            Value::Object(object) => Some(object),
+            _ => {
+                self.report(
+                    path,
+                    format!("expected an object, found {}", describe(value)),
+                );
+                None
+            }
+        }
+    }
+
+    fn expect_string_field(
+        &mut self,
+        object: &Map<String, Value>,
+        path: &str,
+        key: &str,
+        required: bool,
+    ) {
+        match object.get(key) {
+            Some(Value::String(_)) => {}
+            Some(other) => self.report(
+                &key_path(path, key),
+                format!("expected a string, found {}", describe(other)),
+            ),
+            None if required => self.report(path, format!("missing required key `{}`", key)),
+            None => {}
+        }
+    }
+
+    fn expect_string_array_field(&mut self, object: &Map<String, Value>, path: &str, key: &str) {
+        match object.get(key) {
+            Some(Value::Array(items)) if items.iter().all(Value::is_string) => {}
+            Some(_) => self.report(&key_path(path, key), "expected an array of strings"),
+            None => {}
+        }
+    }
+
+    fn expect_integer_field(&mut self, object: &Map<String, Value>, path: &str, key: &str) {
+        match object.get(key) {
+            Some(Value::Number(number))
+                if number.as_i64().is_some_and(|n| i32::try_from(n).is_ok()) => {}
+            Some(_) => self.report(&key_path(path, key), "expected an integer"),
+            None => {}
+        }
+    }
+
+    fn check_keys(&mut self, object: &Map<String, Value>, path: &str, known: &[&str]) {
+        for key in object.keys() {
+            if !known.contains(&key.as_str()) {
+                self.report(
+                    &key_path(path, key),
+                    format!(
+                        "unknown key `{}`, expected one of: {}",
+                        key,
+                        known.join(", ")
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn index_path(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+fn key_path(path: &str, key: &str) -> String {
+    let is_identifier = !key.is_empty()
+        && !key.starts_with(|c: char| c.is_ascii_digit())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        format!("{}.{}", path, key)
+    } else {
+        format!("{}[{}]", path, Value::String(key.to_string()))
+    }
+}
+
+fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(config: &str) -> Vec<String> {
+        validate_config(config)
+            .into_iter()
+            .map(|diagnostic| diagnostic.path)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config() {
+        let config = r#"{
+            "imports": [
+                "react",
+                { "@vueuse/core": ["useMouse", ["useFetch", "useMyFetch"]] },
+                { "from": "react", "type": true, "imports": ["FC", ["useMemo", "useMemoized"], { "name": "useEffect" }] },
+                [{ "name": "default", "as": "axios", "from": "axios" }]
+            ],
+            "debug": true
+        }"#;
+        assert!(validate_config(config).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_keys() {
+        assert_eq!(paths(r#"{ "import": ["react"] }"#), vec!["$.import"]);
+        assert_eq!(
+            paths(r#"{ "imports": [[{ "name": "ref", "form": "vue" }]] }"#),
+            vec!["$.imports[0][0].form", "$.imports[0][0]"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_preset() {
+        let diagnostics = validate_config(r#"{ "imports": ["react", "reakt"] }"#);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "$.imports[1]");
+        assert_eq!(diagnostics[0].message, "unknown preset `reakt`");
+    }
+
+    #[test]
+    fn test_bad_tuples() {
+        assert_eq!(
+            paths(r#"{ "imports": { "from": "react", "imports": [["a", "b", "c", "d"]] } }"#),
+            vec!["$.imports.imports[0]"]
+        );
+        assert_eq!(
+            paths(r#"{ "imports": [{ "@vueuse/core": [["useFetch"]] }] }"#),
+            vec![r#"$.imports[0]["@vueuse/core"][0]"#]
+        );
+    }
+
+    #[test]
+    fn test_legacy_presets() {
+        assert!(validate_config(r#"{ "presets": ["vue", "react"] }"#).is_empty());
+        assert_eq!(
+            paths(r#"{ "presets": ["vue", 1, "vuex"] }"#),
+            vec!["$.presets[1]", "$.presets[2]"]
+        );
+        // Definitions belong in `customPresets`
+        assert_eq!(
+            paths(r#"{ "presets": { "app": ["react"] } }"#),
+            vec!["$.presets"]
+        );
+    }
+
+    #[test]
+    fn test_preset_registry() {
+        let config = r#"{
+            "imports": ["design-system"],
+            "customPresets": {
+                "design-system": [{ "from": "@acme/ui", "imports": ["Button"] }, "reakt"],
+                "broken": { "form": "@acme/ui" },
+                "missing": "does/not/exist.json"
+            }
+        }"#;
+        assert_eq!(
+            paths(config),
+            vec![
+                "$.customPresets.broken.form",
+                r#"$.customPresets["design-system"][1]"#,
+                "$.customPresets.missing",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_directives() {
+        let config = r#"{
+            "rsc": true,
+            "directives": {
+                "default": { "deny": ["useState", "react-dom"] },
+                "use client": { "allow": ["useState"], "deny": "useEffect" },
+                "use server": ["useState"]
+            }
+        }"#;
+        assert_eq!(
+            paths(config),
+            vec![
+                r#"$.directives["use client"].deny"#,
+                r#"$.directives["use server"]"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_file_filters() {
+        let config = r#"{
+            "include": ["src/**"],
+            "exclude": "**/node_modules/**",
+            "imports": [
+                { "preset": "vitest", "include": ["**/*.test.ts"] },
+                { "preset": "vitestt", "exclude": [1] },
+                { "from": "@acme/ui", "imports": ["Button"], "include": ["app/**"] }
+            ]
+        }"#;
+        assert_eq!(
+            paths(config),
+            vec!["$.imports[1].preset", "$.imports[1].exclude", "$.exclude"]
+        );
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let config = r#"{
+            "imports": [
+                { "preset": "@vueuse/core", "priority": 1 },
+                { "from": "ahooks", "imports": ["useDebounce"], "priority": 1.5 }
+            ],
+            "ignore": ["useDebounce"],
+            "conflicts": "last"
+        }"#;
+        assert_eq!(paths(config), vec!["$.imports[1].priority", "$.conflicts"]);
+    }
+
+    #[test]
+    fn test_wrong_shapes() {
+        assert_eq!(
+            paths(r#"{ "imports": 1, "debug": "yes" }"#),
+            vec!["$.imports", "$.debug"]
+        );
+        assert_eq!(paths("[]"), vec!["$"]);
+        assert_eq!(paths("{"), vec!["$"]);
+    }
+}
@@ -1,177 +1,131 @@
 use std::collections::HashMap;
-use swc_core::common::{Mark, SyntaxContext, DUMMY_SP};
+use std::fmt;
+use std::sync::Arc;
+use swc_core::atoms::Atom;
+use swc_core::common::{Mark, Span, SyntaxContext, DUMMY_SP};
 use swc_core::ecma::{
     ast::*,
     visit::{VisitMut, VisitWith},
 };
 
 use crate::collector::IdentifierCollector;
-use crate::config::{
-    Arrayable, ImportConfig, ImportItem, ImportSource, InlinePreset, PluginConfig, PresetImport,
+use crate::config::{ConflictPolicy, PluginConfig};
+use crate::normalize::{
+    tied_exports, ExportKind, IndexedExport, NormalizedConfig, DEFAULT_DIRECTIVE,
 };
-use crate::presets::get_preset_imports;
+use crate::pragma::Pragmas;
+use crate::registry::PresetRegistry;
+use crate::validation::ConfigDiagnostic;
+
+/// An auto import that was reported instead of added
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportDiagnostic {
+    /// First usage of the identifier in the module
+    pub span: Span,
+    pub message: String,
+}
+
+impl fmt::Display for ImportDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
 
 /// Main transform visitor
 pub struct AutoImportVisitor {
-    /// Import map: source -> [(name, alias)]
-    import_map: HashMap<String, Vec<(String, Option<String>)>>,
+    /// Normalized configuration, shared between files
+    config: Arc<NormalizedConfig>,
     /// Unresolved mark for proper syntax context
     unresolved_mark: Mark,
-    /// Enable debug logging
-    debug: bool,
+    /// Imports that were reported instead of added because of a directive filter
+    /// or a name conflict
+    diagnostics: Vec<ImportDiagnostic>,
+    /// In-file pragmas of the module being transformed
+    pragmas: Pragmas,
 }
 
 impl AutoImportVisitor {
     pub fn new(config: PluginConfig, unresolved_mark: Mark) -> Self {
-        let debug = config.debug;
-        let mut import_map = HashMap::new();
-
-        // Process imports (Option<Arrayable<ImportConfig>>)
-        if let Some(imports) = config.imports {
-            // Convert Arrayable to Vec for iteration
-            let imports_vec = match imports {
-                Arrayable::Single(item) => vec![item],
-                Arrayable::Array(items) => items,
-            };
+        Self::new_for_file(config, unresolved_mark, None)
+    }
 
-            for import_config in imports_vec {
-                Self::process_import_config(&mut import_map, import_config);
-            }
-        }
+    /// Create a visitor for a file, applying the `include` / `exclude` globs
+    ///
+    /// `filename` should be relative to the working directory. Without a filename
+    /// every glob filter passes.
+    pub fn new_for_file(
+        config: PluginConfig,
+        unresolved_mark: Mark,
+        filename: Option<&str>,
+    ) -> Self {
+        let registry = PresetRegistry::new(&config.custom_presets);
+        let config = NormalizedConfig::new(&config, &registry, filename);
+        Self::from_normalized(Arc::new(config), unresolved_mark)
+    }
 
+    /// Create a visitor from an already normalized configuration
+    pub fn from_normalized(config: Arc<NormalizedConfig>, unresolved_mark: Mark) -> Self {
         Self {
-            import_map,
+            config,
             unresolved_mark,
-            debug,
+            diagnostics: Vec::new(),
+            pragmas: Pragmas::default(),
         }
     }
 
-    /// Process a single ImportConfig and add to import_map
-    fn process_import_config(
-        import_map: &mut HashMap<String, Vec<(String, Option<String>)>>,
-        import_config: ImportConfig,
-    ) {
-        match import_config {
-            // PresetName: Simple string form like "react", "vue", "react-dom"
-            ImportConfig::PresetName(preset) => {
-                let preset_imports = get_preset_imports(&preset);
-                for (source, imports) in preset_imports {
-                    import_map
-                        .entry(source)
-                        .or_insert_with(Vec::new)
-                        .extend(imports);
-                }
-            }
-            // InlinePreset: { from: "react", imports: ["useState", "useEffect"] }
-            ImportConfig::InlinePreset(inline_preset) => {
-                Self::process_inline_preset(import_map, inline_preset);
-            }
-            // Explicit form (legacy): [{ name: "ref", from: "vue" }, ...]
-            ImportConfig::Explicit(items) => {
-                for item in items {
-                    import_map
-                        .entry(item.from.clone())
-                        .or_insert_with(Vec::new)
-                        .push((item.name.clone(), item.alias.clone()));
-                }
-            }
-            // ImportsMap: { "package": ["export1", "export2"] }
-            ImportConfig::ImportsMap(map) => {
-                for (source, import_source) in map {
-                    let import_list: Vec<(String, Option<String>)> = match import_source {
-                        ImportSource::Simple(names) => {
-                            names.iter().map(|name| (name.clone(), None)).collect()
-                        }
-                        ImportSource::WithAlias(items) => items
-                            .iter()
-                            .map(|item| match item {
-                                ImportItem::Simple(name) => (name.clone(), None),
-                                ImportItem::Aliased([name, alias]) => {
-                                    (name.clone(), Some(alias.clone()))
-                                }
-                            })
-                            .collect(),
-                    };
-
-                    import_map
-                        .entry(source.clone())
-                        .or_insert_with(Vec::new)
-                        .extend(import_list);
-                }
-            }
-        }
+    /// Apply the in-file pragmas read from the module's comments
+    pub fn with_pragmas(mut self, pragmas: Pragmas) -> Self {
+        self.pragmas = pragmas;
+        self
     }
 
-    /// Process an InlinePreset and add to import_map
-    fn process_inline_preset(
-        import_map: &mut HashMap<String, Vec<(String, Option<String>)>>,
-        inline_preset: InlinePreset,
-    ) {
-        let source = inline_preset.from;
+    /// Problems found while normalizing the import maps
+    pub fn warnings(&self) -> &[ConfigDiagnostic] {
+        self.config.warnings()
+    }
 
-        for preset_import in inline_preset.imports {
-            Self::process_preset_import(import_map, preset_import, &source);
-        }
+    /// Imports that were reported instead of added because of a directive filter
+    /// or a name conflict
+    pub fn diagnostics(&self) -> &[ImportDiagnostic] {
+        &self.diagnostics
     }
 
-    /// Process a PresetImport and add to import_map
-    fn process_preset_import(
-        import_map: &mut HashMap<String, Vec<(String, Option<String>)>>,
-        preset_import: PresetImport,
-        default_source: &str,
-    ) {
-        match preset_import {
-            // Simple string: "useState"
-            PresetImport::Simple(name) => {
-                import_map
-                    .entry(default_source.to_string())
-                    .or_insert_with(Vec::new)
-                    .push((name, None));
-            }
-            // Tuple: ["useState", "useSignal"] or ["useState", "useSignal", "react"]
-            PresetImport::Tuple(parts) => {
-                let (name, alias, source) = match parts.len() {
-                    1 => (parts[0].clone(), None, default_source.to_string()),
-                    2 => (
-                        parts[0].clone(),
-                        Some(parts[1].clone()),
-                        default_source.to_string(),
-                    ),
-                    3 => (parts[0].clone(), Some(parts[1].clone()), parts[2].clone()),
-                    _ => return, // Invalid tuple length
-                };
-
-                import_map
-                    .entry(source)
-                    .or_insert_with(Vec::new)
-                    .push((name, alias));
-            }
-            // Object: { name: "useState", as?: "useSignal" }
-            PresetImport::Object { name, alias } => {
-                import_map
-                    .entry(default_source.to_string())
-                    .or_insert_with(Vec::new)
-                    .push((name, alias));
-            }
-            // Nested InlinePreset
-            PresetImport::Nested(nested) => {
-                Self::process_inline_preset(import_map, *nested);
-            }
-        }
+    /// The first directive of the module other than "use strict"
+    fn module_directive(module: &Module) -> Option<String> {
+        module
+            .body
+            .iter()
+            .map_while(|item| match item {
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+                    Expr::Lit(Lit::Str(Str { value, .. })) => {
+                        value.as_atom().map(ToString::to_string)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .find(|directive| directive != "use strict")
     }
 
     /// Add auto imports to the module
-    fn add_auto_imports(&self, module: &mut Module) {
+    fn add_auto_imports(&mut self, module: &mut Module) {
+        // `// @auto-import-disable`
+        if self.pragmas.disabled {
+            return;
+        }
+
         // Collect identifier information
-        let mut collector = IdentifierCollector::new();
+        let mut collector = IdentifierCollector::new(self.unresolved_mark);
         module.visit_with(&mut collector);
 
         // Debug: print collected identifiers (only if debug is enabled)
-        if self.debug {
+        if self.config.debug {
             // Collect all debug info in a single string to avoid interleaved output
             let debug_info = format!(
                 "[DEBUG] Used identifiers count: {}\n\
                  [DEBUG] Used identifiers (sample): {:?}\n\
+                 [DEBUG] Type identifiers count: {}\n\
+                 [DEBUG] Type identifiers (sample): {:?}\n\
                  [DEBUG] Imported identifiers count: {}\n\
                  [DEBUG] Imported identifiers (sample): {:?}\n\
                  [DEBUG] Declared identifiers count: {}\n\
@@ -182,6 +136,12 @@ impl AutoImportVisitor {
                     .iter()
                     .take(10)
                     .collect::<Vec<_>>(),
+                collector.type_identifiers.len(),
+                collector
+                    .type_identifiers
+                    .iter()
+                    .take(10)
+                    .collect::<Vec<_>>(),
                 collector.imported_identifiers.len(),
                 collector
                     .imported_identifiers
@@ -199,33 +159,142 @@ impl AutoImportVisitor {
             eprintln!("{}", debug_info);
         }
 
-        // Find identifiers that need to be auto-imported
-        let mut imports_to_add: HashMap<String, Vec<(String, Option<String>)>> = HashMap::new();
+        // Find identifiers that need to be auto-imported: source -> [(name, alias, type_only)]
+        let mut imports_to_add: HashMap<String, Vec<(String, Option<String>, bool)>> =
+            HashMap::new();
         let mut skipped_already_imported = Vec::new();
         let mut skipped_already_declared = Vec::new();
+        let mut rejected = Vec::new();
+
+        let directive = Self::module_directive(module);
+        let filter = self
+            .config
+            .directive_filters
+            .get(directive.as_deref().unwrap_or(DEFAULT_DIRECTIVE));
+
+        // Look up only the identifiers the module uses, in a stable order
+        let mut used_names: Vec<&Atom> = collector
+            .used_identifiers
+            .iter()
+            .chain(&collector.type_identifiers)
+            .collect();
+        used_names.sort();
+        used_names.dedup();
+
+        let mut conflicts: Vec<(String, Span, Vec<String>)> = Vec::new();
+
+        for local_name in used_names {
+            let Some(exports) = self.config.index.get(local_name) else {
+                continue;
+            };
+            let span = collector.spans.get(local_name).copied().unwrap_or(DUMMY_SP);
+
+            // Type exports satisfy type positions. A value export is not a type, in
+            // type positions it only satisfies `typeof` queries
+            let used_as_type = collector.type_identifiers.contains(local_name);
+            let refers_to_value = collector.used_identifiers.contains(local_name)
+                || collector.type_query_identifiers.contains(local_name);
+            let exports: Vec<&IndexedExport> = exports
+                .iter()
+                .filter(|export| match export.kind {
+                    ExportKind::Value => refers_to_value,
+                    ExportKind::Type => used_as_type,
+                })
+                // `// @auto-import-only`
+                .filter(|export| self.pragmas.permits(local_name, &export.source))
+                .collect();
+
+            // Exports are sorted by kind and rank, the first one wins
+            let Some(export) = exports.first().copied() else {
+                continue;
+            };
 
-        for (source, available_imports) in &self.import_map {
-            for (name, alias) in available_imports {
-                let local_name = alias.as_ref().unwrap_or(name);
-
-                // If identifier is used but not imported or declared, add import
-                if collector.used_identifiers.contains(local_name) {
-                    if collector.imported_identifiers.contains(local_name) {
-                        skipped_already_imported.push((local_name.clone(), source.clone()));
-                    } else if collector.declared_identifiers.contains(local_name) {
-                        skipped_already_declared.push((local_name.clone(), source.clone()));
-                    } else {
-                        imports_to_add
-                            .entry(source.clone())
-                            .or_insert_with(Vec::new)
-                            .push((name.clone(), alias.clone()));
+            // If identifier is used but not imported or declared, add import
+            if collector.imported_identifiers.contains(local_name) {
+                skipped_already_imported.push((local_name.to_string(), export.source.clone()));
+                continue;
+            }
+            if collector.declared_identifiers.contains(local_name) {
+                skipped_already_declared.push((local_name.to_string(), export.source.clone()));
+                continue;
+            }
+
+            let sources: Vec<String> = match self.config.conflicts {
+                // A strictly higher priority still wins, only ties are refused
+                ConflictPolicy::Error => tied_exports(exports.iter().copied()),
+                ConflictPolicy::Priority => {
+                    let mut sources: Vec<String> = Vec::new();
+                    for other in exports.iter().filter(|other| other.kind == export.kind) {
+                        if !sources.contains(&other.source) {
+                            sources.push(other.source.clone());
+                        }
                     }
+                    sources
                 }
+            };
+            if sources.len() > 1 {
+                conflicts.push((local_name.to_string(), span, sources));
+                if self.config.conflicts == ConflictPolicy::Error {
+                    continue;
+                }
+            }
+
+            let used_as_value =
+                export.kind == ExportKind::Value && collector.used_identifiers.contains(local_name);
+
+            // Type-only imports are erased, directive filters only apply to values
+            if used_as_value
+                && filter
+                    .is_some_and(|filter| !filter.permits(&export.name, local_name, &export.source))
+            {
+                // Point at the value usage the directive rejects, not a type usage before it
+                let span = collector
+                    .value_spans
+                    .get(local_name)
+                    .copied()
+                    .unwrap_or(span);
+                rejected.push((local_name.to_string(), export.source.clone(), span));
+            } else {
+                // Identifiers only used in type positions get a type-only import
+                imports_to_add
+                    .entry(export.source.clone())
+                    .or_default()
+                    .push((export.name.clone(), export.alias.clone(), !used_as_value));
+            }
+        }
+
+        // Report names provided by several sources, already formatted by `tied_exports`
+        if self.config.conflicts == ConflictPolicy::Error {
+            for (local_name, span, sources) in &conflicts {
+                self.diagnostics.push(ImportDiagnostic {
+                    span: *span,
+                    message: format!(
+                        "`{}` is provided by {} with the same priority, give one of them a higher `priority` or add it to `ignore`",
+                        local_name,
+                        sources.join(", ")
+                    ),
+                });
             }
         }
 
+        // Report imports the module's directive doesn't permit
+        rejected.sort();
+        for (local_name, source, span) in rejected {
+            let location = match &directive {
+                Some(directive) => format!("\"{}\" modules", directive),
+                None => "modules without a directive".to_string(),
+            };
+            self.diagnostics.push(ImportDiagnostic {
+                span,
+                message: format!(
+                    "`{}` from `{}` is not allowed in {}",
+                    local_name, source, location
+                ),
+            });
+        }
+
         // Debug: print auto-import decisions
-        if self.debug {
+        if self.config.debug {
             let mut auto_import_info = String::new();
 
             if !imports_to_add.is_empty() {
@@ -239,6 +308,18 @@ impl AutoImportVisitor {
                 auto_import_info.push_str("\n[AUTO-IMPORT] No imports to add\n");
             }
 
+            if self.config.conflicts == ConflictPolicy::Priority && !conflicts.is_empty() {
+                auto_import_info.push_str("\n[CONFLICT] Provided by several sources:\n");
+                for (name, _, sources) in &conflicts {
+                    auto_import_info.push_str(&format!(
+                        "  '{}' from '{}' (also in {:?})\n",
+                        name,
+                        sources[0],
+                        &sources[1..]
+                    ));
+                }
+            }
+
             if !skipped_already_imported.is_empty() {
                 auto_import_info.push_str("\n[SKIPPED] Already imported in current file:\n");
                 skipped_already_imported.sort();
@@ -274,73 +355,15 @@ impl AutoImportVisitor {
             // Sort imports within each source alphabetically by name
             imports.sort_by(|a, b| a.0.cmp(&b.0));
 
-            let specifiers = imports
-                .into_iter()
-                .map(|(name, alias)| {
-                    // Handle default imports: { name: "default", as: "_", from: "lodash" }
-                    if name == "default" {
-                        // Default import: import alias_name from "source"
-                        let local_name = alias.unwrap_or_else(|| "default".to_string());
-                        return ImportSpecifier::Default(ImportDefaultSpecifier {
-                            span: DUMMY_SP,
-                            local: Ident::new(local_name.into(), DUMMY_SP, unresolved_ctxt),
-                        });
-                    }
-
-                    // Handle namespace imports: { name: "*", as: "_", from: "lodash" }
-                    if name == "*" {
-                        // Namespace import: import * as alias_name from "source"
-                        let local_name = alias.unwrap_or_else(|| "default".to_string());
-                        return ImportSpecifier::Namespace(ImportStarAsSpecifier {
-                            span: DUMMY_SP,
-                            local: Ident::new(local_name.into(), DUMMY_SP, unresolved_ctxt),
-                        });
-                    }
-
-                    match alias {
-                        Some(alias_name) => {
-                            // Import with alias: import { name as alias_name } from "source"
-                            ImportSpecifier::Named(ImportNamedSpecifier {
-                                span: DUMMY_SP,
-                                local: Ident::new(
-                                    alias_name.clone().into(),
-                                    DUMMY_SP,
-                                    unresolved_ctxt,
-                                ),
-                                imported: Some(ModuleExportName::Ident(Ident::new(
-                                    name.into(),
-                                    DUMMY_SP,
-                                    unresolved_ctxt,
-                                ))),
-                                is_type_only: false,
-                            })
-                        }
-                        None => {
-                            // Simple import: import { name } from "source"
-                            // Both local and imported should be None (or same value)
-                            ImportSpecifier::Named(ImportNamedSpecifier {
-                                span: DUMMY_SP,
-                                local: Ident::new(name.clone().into(), DUMMY_SP, unresolved_ctxt),
-                                imported: None,
-                                is_type_only: false,
-                            })
-                        }
-                    }
-                })
-                .collect();
+            // Append to an existing declaration first; only leftovers get a new one
+            if self.config.merge_existing {
+                imports = Self::merge_into_existing(module, &source, imports, unresolved_ctxt);
+                if imports.is_empty() {
+                    continue;
+                }
+            }
 
-            new_imports.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                span: DUMMY_SP,
-                specifiers,
-                src: Box::new(Str {
-                    span: DUMMY_SP,
-                    value: source.into(),
-                    raw: None,
-                }),
-                type_only: false,
-                with: None,
-                phase: Default::default(),
-            })));
+            new_imports.extend(Self::create_import_decls(&source, imports, unresolved_ctxt));
         }
 
         // Add new imports to the top of the module, after any directives
@@ -363,10 +386,225 @@ impl AutoImportVisitor {
             // Insert imports at the calculated position
             let mut items = module.body.drain(..insert_position).collect::<Vec<_>>();
             items.extend(new_imports);
-            items.extend(module.body.drain(..));
+            items.append(&mut module.body);
             module.body = items;
         }
     }
+
+    /// Append imports to the existing declarations from `source`
+    ///
+    /// Each import goes to the first declaration that can hold it. Returns the
+    /// imports that cannot be merged: namespace imports, a second default import,
+    /// and anything no declaration can hold. Type-only declarations take no value
+    /// or default imports, and namespace declarations take no more specifiers.
+    fn merge_into_existing(
+        module: &mut Module,
+        source: &str,
+        imports: Vec<(String, Option<String>, bool)>,
+        ctxt: SyntaxContext,
+    ) -> Vec<(String, Option<String>, bool)> {
+        let mut existing: Vec<&mut ImportDecl> = module
+            .body
+            .iter_mut()
+            .filter_map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(decl))
+                    if decl.phase == ImportPhase::Evaluation
+                        && decl.with.is_none()
+                        && decl
+                            .src
+                            .value
+                            .as_atom()
+                            .is_some_and(|value| &**value == source) =>
+                {
+                    Some(decl)
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut remaining = Vec::new();
+        for (name, alias, type_only) in imports {
+            let Some(decl) = existing
+                .iter_mut()
+                .find(|decl| Self::accepts(decl, &name, type_only))
+            else {
+                remaining.push((name, alias, type_only));
+                continue;
+            };
+
+            if name == "default" {
+                // Default specifier must come first: `import React, { useState }`
+                let specifier = Self::create_import_specifier(name, alias, false, ctxt);
+                decl.specifiers.insert(0, specifier);
+            } else {
+                // Inside `import type { ... }` specifiers need no inline `type`
+                let inline_type = type_only && !decl.type_only;
+                let specifier = Self::create_import_specifier(name, alias, inline_type, ctxt);
+                decl.specifiers.push(specifier);
+            }
+        }
+
+        remaining
+    }
+
+    /// Whether an import of `name` can be added to an existing declaration
+    fn accepts(decl: &ImportDecl, name: &str, type_only: bool) -> bool {
+        let has_namespace = decl
+            .specifiers
+            .iter()
+            .any(|specifier| matches!(specifier, ImportSpecifier::Namespace(_)));
+        let has_default = decl
+            .specifiers
+            .iter()
+            .any(|specifier| matches!(specifier, ImportSpecifier::Default(_)));
+
+        match name {
+            _ if has_namespace => false,
+            "*" => false,
+            "default" => !has_default && !type_only && !decl.type_only,
+            // `import type A, { B }` is not allowed either
+            _ => !decl.type_only || (type_only && !has_default),
+        }
+    }
+
+    /// Create an import specifier for an export name and optional local alias
+    fn create_import_specifier(
+        name: String,
+        alias: Option<String>,
+        is_type_only: bool,
+        ctxt: SyntaxContext,
+    ) -> ImportSpecifier {
+        // Handle default imports: { name: "default", as: "_", from: "lodash" }
+        if name == "default" {
+            // Default import: import alias_name from "source"
+            let local_name = alias.unwrap_or_else(|| "default".to_string());
+            return ImportSpecifier::Default(ImportDefaultSpecifier {
+                span: DUMMY_SP,
+                local: Ident::new(local_name.into(), DUMMY_SP, ctxt),
+            });
+        }
+
+        // Handle namespace imports: { name: "*", as: "_", from: "lodash" }
+        if name == "*" {
+            // Namespace import: import * as alias_name from "source"
+            let local_name = alias.unwrap_or_else(|| "default".to_string());
+            return ImportSpecifier::Namespace(ImportStarAsSpecifier {
+                span: DUMMY_SP,
+                local: Ident::new(local_name.into(), DUMMY_SP, ctxt),
+            });
+        }
+
+        match alias {
+            Some(alias_name) => {
+                // Import with alias: import { name as alias_name } from "source"
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local: Ident::new(alias_name.into(), DUMMY_SP, ctxt),
+                    imported: Some(ModuleExportName::Ident(Ident::new(
+                        name.into(),
+                        DUMMY_SP,
+                        ctxt,
+                    ))),
+                    is_type_only,
+                })
+            }
+            None => {
+                // Simple import: import { name } from "source"
+                // Both local and imported should be None (or same value)
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local: Ident::new(name.into(), DUMMY_SP, ctxt),
+                    imported: None,
+                    is_type_only,
+                })
+            }
+        }
+    }
+
+    /// Create the import declarations for all imports from one source
+    ///
+    /// A declaration holds at most one default specifier followed by either named
+    /// specifiers or a single namespace specifier, so incompatible kinds are split:
+    /// - default + named share one declaration: `import React, { useState } from 'react'`
+    /// - every namespace and every additional default gets its own declaration
+    /// - named types are inlined (`type FC`) when a value declaration exists,
+    ///   otherwise they go to `import type { ... }`
+    /// - type-only default and namespace specifiers get their own `import type`
+    fn create_import_decls(
+        source: &str,
+        imports: Vec<(String, Option<String>, bool)>,
+        ctxt: SyntaxContext,
+    ) -> Vec<ModuleItem> {
+        let inline_types = imports
+            .iter()
+            .any(|(name, _, type_only)| !type_only && name != "*");
+
+        let mut defaults = Vec::new();
+        let mut namespaces = Vec::new();
+        let mut named = Vec::new();
+        let mut type_defaults = Vec::new();
+        let mut type_namespaces = Vec::new();
+        let mut type_named = Vec::new();
+
+        for (name, alias, type_only) in imports {
+            let target = match (name.as_str(), type_only) {
+                ("default", false) => &mut defaults,
+                ("*", false) => &mut namespaces,
+                ("default", true) => &mut type_defaults,
+                ("*", true) => &mut type_namespaces,
+                (_, true) if !inline_types => &mut type_named,
+                _ => &mut named,
+            };
+            target.push(Self::create_import_specifier(
+                name,
+                alias,
+                type_only && inline_types,
+                ctxt,
+            ));
+        }
+
+        let mut decls = Vec::new();
+        let mut defaults = defaults.into_iter();
+
+        // Combined form: default first, then named specifiers
+        let mut combined: Vec<_> = defaults.next().into_iter().collect();
+        combined.extend(named);
+        if !combined.is_empty() {
+            decls.push(Self::create_import_decl(source, combined, false));
+        }
+        for specifier in defaults.chain(namespaces) {
+            decls.push(Self::create_import_decl(source, vec![specifier], false));
+        }
+
+        if !type_named.is_empty() {
+            decls.push(Self::create_import_decl(source, type_named, true));
+        }
+        for specifier in type_defaults.into_iter().chain(type_namespaces) {
+            decls.push(Self::create_import_decl(source, vec![specifier], true));
+        }
+
+        decls
+    }
+
+    /// Create an import declaration for a source
+    fn create_import_decl(
+        source: &str,
+        specifiers: Vec<ImportSpecifier>,
+        type_only: bool,
+    ) -> ModuleItem {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+            span: DUMMY_SP,
+            specifiers,
+            src: Box::new(Str {
+                span: DUMMY_SP,
+                value: source.into(),
+                raw: None,
+            }),
+            type_only,
+            with: None,
+            phase: Default::default(),
+        }))
+    }
 }
 
 impl VisitMut for AutoImportVisitor {
@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// Run the `swc-auto-import` binary from the crate root
+fn cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_swc-auto-import"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to run swc-auto-import")
+}
+
+/// Write a config to a temp file unique to the test
+fn config_file(test: &str, config: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "swc-auto-import-cli-{}-{}.json",
+        std::process::id(),
+        test
+    ));
+    std::fs::write(&path, config).unwrap();
+    path
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).to_string()
+}
+
+#[test]
+fn check_priority_conflicts_succeed() {
+    let output = cli(&[
+        "check",
+        "--config",
+        "tests/fixture/conflict-priority/config.json",
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "config is valid\n");
+    assert!(stderr(&output).contains(
+        "warning: `debounce` is provided by `es-toolkit`, `lodash-es`, `es-toolkit` wins"
+    ));
+}
+
+#[test]
+fn check_conflict_error_fails_on_ties_only() {
+    let config = config_file(
+        "conflict-error",
+        r#"{
+            "imports": [
+                { "ahooks": ["useDebounce"] },
+                { "@vueuse/core": ["useDebounce"] },
+                { "from": "lodash-es", "imports": ["debounce"], "priority": 1 },
+                { "es-toolkit": ["debounce"] }
+            ],
+            "conflicts": "error"
+        }"#,
+    );
+    let output = cli(&["check", "--config", &config.to_string_lossy()]);
+    std::fs::remove_file(&config).unwrap();
+
+    assert!(!output.status.success());
+    let stderr = stderr(&output);
+    assert!(stderr.contains(
+        "error: `useDebounce` is provided by `ahooks`, `@vueuse/core` with the same priority"
+    ));
+    assert!(stderr.contains("warning: `debounce` is provided by `lodash-es`, `es-toolkit`"));
+    assert!(stderr.contains("error: found 1 problem"));
+}
+
+#[test]
+fn check_conflicting_aliases_fail() {
+    let config = config_file(
+        "conflicting-aliases",
+        r#"{ "imports": [{ "react": [["useState", "x"], ["useEffect", "x"]] }], "conflicts": "error" }"#,
+    );
+    let output = cli(&["check", "--config", &config.to_string_lossy()]);
+    std::fs::remove_file(&config).unwrap();
+
+    assert!(!output.status.success());
+    let stderr = stderr(&output);
+    assert!(
+        stderr.contains("warning: $.imports[0]: `x` from `react` is an alias of several exports")
+    );
+    assert!(stderr.contains(
+        "error: `x` is provided by `react` (`useState`), `react` (`useEffect`) with the same priority"
+    ));
+}
+
+#[test]
+fn check_invalid_config_fails() {
+    let config = config_file("invalid", r#"{ "imports": ["reakt"] }"#);
+    let output = cli(&["check", "--config", &config.to_string_lossy()]);
+    std::fs::remove_file(&config).unwrap();
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("error: invalid config at $.imports[0]: unknown preset `reakt`")
+    );
+}
+
+#[test]
+fn transform_prints_output() {
+    let output = cli(&[
+        "transform",
+        "--config",
+        "tests/fixture/conflict-priority/config.json",
+        "tests/fixture/conflict-priority/input.ts",
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let expected = std::fs::read_to_string("tests/fixture/conflict-priority/output.js").unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        expected.trim()
+    );
+}
+
+#[test]
+fn transform_fails_on_rejected_imports() {
+    let config = config_file("rsc", r#"{ "imports": ["react"], "rsc": true }"#);
+    let file = std::env::temp_dir().join(format!(
+        "swc-auto-import-cli-{}-action.ts",
+        std::process::id()
+    ));
+    std::fs::write(&file, "'use server';\nconst [count] = useState(0);\n").unwrap();
+    let output = cli(&[
+        "transform",
+        "--config",
+        &config.to_string_lossy(),
+        &file.to_string_lossy(),
+    ]);
+    std::fs::remove_file(&config).unwrap();
+    std::fs::remove_file(&file).unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("'use server';\nconst [count]"));
+    assert!(stderr(&output)
+        .contains(r#"error: `useState` from `react` is not allowed in "use server" modules"#));
+}
+
+#[test]
+fn dts_reports_invalid_config() {
+    let config = config_file(
+        "dts-invalid",
+        r#"{ "imports": ["reakt", { "ahooks": ["useMount"] }] }"#,
+    );
+    let output = cli(&["dts", "--config", &config.to_string_lossy()]);
+    std::fs::remove_file(&config).unwrap();
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("useMount"));
+    assert!(
+        stderr(&output).contains("warning: invalid config at $.imports[0]: unknown preset `reakt`")
+    );
+}
+
+#[test]
+fn eslint_fails_on_invalid_config_in_strict_mode() {
+    let config = config_file(
+        "eslint-strict",
+        r#"{ "imports": ["reakt"], "strict": true }"#,
+    );
+    let output = cli(&["eslint", "--config", &config.to_string_lossy()]);
+    std::fs::remove_file(&config).unwrap();
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(stderr(&output).contains("error: invalid config in strict mode"));
+}
@@ -0,0 +1,20 @@
+const { ref } = createStore();
+const [computed] = createComputed();
+declare const watch: (source: unknown) => void;
+enum reactive { On, Off }
+namespace toRef {
+  export const value = 1;
+}
+import nextTick = require('./next-tick');
+
+try {
+  ref(0);
+} catch (unref) {
+  console.log(unref);
+}
+
+const Handler = class isRef {};
+const unwrap = (toRaw = 1) => toRaw;
+computed(() => watch(reactive.On));
+nextTick(toRef.value);
+shallowRef(0);
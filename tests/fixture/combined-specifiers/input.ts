@@ -0,0 +1,7 @@
+const [state, setState] = useState(React.version);
+const element = ReactNS.createElement('div');
+const save = debounce(() => _.noop(), 300);
+axios.get('/api').then(() => http.post('/api'));
+
+let app: VueApp;
+let count: Ref<number>;
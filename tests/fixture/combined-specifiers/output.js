@@ -0,0 +1,14 @@
+import axios from "axios";
+import http from "axios";
+import { debounce } from "lodash";
+import * as _ from "lodash";
+import React, { useState } from "react";
+import * as ReactNS from "react";
+import type { Ref } from "vue";
+import type VueApp from "vue";
+const [state, setState] = useState(React.version);
+const element = ReactNS.createElement('div');
+const save = debounce(()=>_.noop(), 300);
+axios.get('/api').then(()=>http.post('/api'));
+let app: VueApp;
+let count: Ref<number>;
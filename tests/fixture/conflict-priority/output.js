@@ -0,0 +1,8 @@
+import { useDebounce, useRequest } from "ahooks";
+import { debounce, throttle } from "es-toolkit";
+const query = useDebounce(search, {
+    wait: 300
+});
+const { data } = useRequest(fetchUsers);
+const onScroll = throttle(debounce(save, 100), 50);
+const { x, y } = useMouse();
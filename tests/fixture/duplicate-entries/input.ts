@@ -0,0 +1,4 @@
+const [count, setCount] = useState(0);
+const doubled = useMemoized(() => count * 2, [count]);
+
+useEffect(() => setCount(doubled), [doubled]);
@@ -0,0 +1,8 @@
+import { useEffect, useMemo as useMemoized, useState } from "react";
+const [count, setCount] = useState(0);
+const doubled = useMemoized(()=>count * 2, [
+    count
+]);
+useEffect(()=>setCount(doubled), [
+    doubled
+]);
@@ -0,0 +1,6 @@
+const [state] = useState(0);
+
+describe('state', () => {
+  render(cn('root'));
+  track(state);
+});
@@ -0,0 +1,7 @@
+import { track } from "@/analytics";
+import { useState } from "react";
+const [state] = useState(0);
+describe('state', ()=>{
+    render(cn('root'));
+    track(state);
+});
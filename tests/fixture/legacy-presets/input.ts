@@ -0,0 +1,2 @@
+const count = ref(0);
+const doubled = computed(() => count.value * 2);
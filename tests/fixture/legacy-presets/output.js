@@ -0,0 +1,3 @@
+import { computed, ref } from "vue";
+const count = ref(0);
+const doubled = computed(()=>count.value * 2);
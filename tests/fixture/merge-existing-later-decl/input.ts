@@ -0,0 +1,9 @@
+import type { ReactNode } from 'react';
+import * as utils from 'lodash';
+import { useEffect } from 'react';
+
+export function App({ children }: { children: ReactNode }) {
+  const [count, setCount] = useState(0);
+  useEffect(() => setCount(utils.add(count, 1)), []);
+  return React.createElement('div', null, children);
+}
@@ -0,0 +1,10 @@
+import * as _ from "lodash";
+import React, { useEffect, type ReactNode, useState } from 'react';
+import * as utils from 'lodash';
+export function App({ children }: {
+    children: ReactNode;
+}) {
+    const [count, setCount] = useState(0);
+    useEffect(()=>setCount(utils.add(count, 1)), []);
+    return React.createElement('div', null, _.identity(children));
+}
@@ -0,0 +1,10 @@
+'use client';
+
+export function Preloader({ src }: { src: string }) {
+  useEffect(() => {
+    const image = new Image();
+    image.src = src;
+  }, [src]);
+
+  return <NextImage src={src} alt="" width={100} height={20} />;
+}
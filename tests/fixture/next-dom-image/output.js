@@ -0,0 +1,14 @@
+'use client';
+import NextImage from "next/image";
+import { useEffect } from "react";
+export function Preloader({ src }: {
+    src: string;
+}) {
+    useEffect(()=>{
+        const image = new Image();
+        image.src = src;
+    }, [
+        src
+    ]);
+    return <NextImage src={src} alt="" width={100} height={20}/>;
+}
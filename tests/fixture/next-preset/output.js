@@ -0,0 +1,16 @@
+'use client';
+import type { Metadata } from "next";
+import NextImage from "next/image";
+import Link from "next/link";
+import { notFound, usePathname, useRouter } from "next/navigation";
+export const metadata: Metadata = {
+    title: 'Home'
+};
+export default function Page() {
+    const router = useRouter();
+    const pathname = usePathname();
+    if (!pathname) notFound();
+    return (<Link href="/" onClick={()=>router.refresh()}>
+      <NextImage src="/next.svg" alt="Next.js" width={100} height={20}/>
+    </Link>);
+}
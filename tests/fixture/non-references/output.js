@@ -0,0 +1,19 @@
+import { Suspense, useId } from "react";
+useEffect: for (const item of items){
+    if (item) break useEffect;
+    continue useEffect;
+}
+const local = 1;
+export { local as useState };
+export { memo } from './memo';
+interface Props {
+    useRef: number;
+    useMemo(): void;
+}
+let reducer: useReducer<Props>;
+export function Counter(): lazy {
+    const id = useId();
+    return (<Suspense>
+      <use-element id={id}/>
+    </Suspense>);
+}
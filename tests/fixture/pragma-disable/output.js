@@ -0,0 +1,4 @@
+const [count, setCount] = useState(0);
+useEffect(()=>setCount(count + 1), [
+    count
+]);
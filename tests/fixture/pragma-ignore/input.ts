@@ -0,0 +1,9 @@
+'use client';
+// @auto-import-ignore useEffect, useRef
+/* @auto-import-only react */
+
+const [count, setCount] = useState(0);
+const el = useRef(null);
+const doubled = computed(() => count * 2);
+
+useEffect(() => setCount(1), []);
@@ -0,0 +1,6 @@
+'use client';
+import { useState } from "react";
+const [count, setCount] = useState(0);
+const el = useRef(null);
+const doubled = computed(()=>count * 2);
+useEffect(()=>setCount(1), []);
@@ -0,0 +1,12 @@
+export function Toolbar() {
+  const [open, setOpen] = useState(false);
+  const toast = useToast();
+
+  return (
+    <Card className={cn('toolbar', open && 'open')}>
+      <Button onClick={() => setOpen(!open)}>
+        <Icon name="menu" />
+      </Button>
+    </Card>
+  );
+}
@@ -0,0 +1,14 @@
+import { useToast } from "@/hooks";
+import { cn } from "@/utils";
+import Icon from "@acme/icons";
+import { Button, Card } from "@acme/ui";
+import { useState } from "react";
+export function Toolbar() {
+    const [open, setOpen] = useState(false);
+    const toast = useToast();
+    return (<Card className={cn('toolbar', open && 'open')}>
+      <Button onClick={()=>setOpen(!open)}>
+        <Icon name="menu"/>
+      </Button>
+    </Card>);
+}
@@ -0,0 +1,14 @@
+'use cache';
+
+type Setter = ReturnType<typeof useState>[1];
+
+export default async function Page() {
+  const theme = (await cookies()).get('theme');
+  const [count] = useState(0);
+
+  return (
+    <Suspense fallback={null}>
+      <Counter theme={theme} count={count} />
+    </Suspense>
+  );
+}
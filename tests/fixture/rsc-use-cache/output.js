@@ -0,0 +1,11 @@
+'use cache';
+import { cookies } from "next/headers";
+import { Suspense } from "react";
+type Setter = ReturnType<typeof useState>[1];
+export default async function Page() {
+    const theme = (await cookies()).get('theme');
+    const [count] = useState(0);
+    return (<Suspense fallback={null}>
+      <Counter theme={theme} count={count}/>
+    </Suspense>);
+}
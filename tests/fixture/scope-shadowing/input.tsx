@@ -0,0 +1,13 @@
+function withCache(memo: (value: number) => number) {
+  return memo(1);
+}
+
+function useCounter() {
+  const useState = (value: number) => [value, () => {}];
+  return useState(0);
+}
+
+const Counter = memo(function Counter() {
+  const [count, setCount] = useState(0);
+  return <div onClick={() => setCount(count + 1)}>{count}</div>;
+});
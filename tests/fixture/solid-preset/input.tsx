@@ -0,0 +1,13 @@
+export const Counter: Component = () => {
+  const [count, setCount] = createSignal(0);
+  const [todos] = createStore<string[]>([]);
+
+  onMount(() => setCount(1));
+  createEffect(() => console.log(count()));
+
+  return (
+    <Show when={count() > 0} fallback={<p>Empty</p>}>
+      <For each={todos}>{(todo) => <li>{todo}</li>}</For>
+    </Show>
+  );
+};
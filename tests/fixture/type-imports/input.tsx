@@ -0,0 +1,6 @@
+const Button: FC<{ children: ReactNode }> = ({ children }) => {
+  const [value, setValue] = useState<string>('');
+  return <button onClick={() => setValue(value)}>{children}</button>;
+};
+
+class Store implements Dispatch<string> {}
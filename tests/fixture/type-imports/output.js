@@ -0,0 +1,9 @@
+import { type Dispatch, type FC, type ReactNode, useState } from "react";
+const Button: FC<{
+    children: ReactNode;
+}> = ({ children })=>{
+    const [value, setValue] = useState<string>('');
+    return <button onClick={()=>setValue(value)}>{children}</button>;
+};
+class Store implements Dispatch<string> {
+}
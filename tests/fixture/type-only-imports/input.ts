@@ -0,0 +1,7 @@
+export interface Counter {
+  count: Ref<number>;
+  doubled: ComputedRef<number>;
+  memoize: typeof memo;
+}
+
+export const Ref = 1;
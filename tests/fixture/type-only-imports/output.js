@@ -0,0 +1,8 @@
+import type { memo } from "react";
+import type { ComputedRef } from "vue";
+export interface Counter {
+    count: Ref<number>;
+    doubled: ComputedRef<number>;
+    memoize: typeof memo;
+}
+export const Ref = 1;
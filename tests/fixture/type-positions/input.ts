@@ -0,0 +1,6 @@
+type Reducer = typeof useReducer;
+let factory: lazy;
+
+export async function GET(request: NextRequest): Promise<Response> {
+  return NextResponse.json({ url: request.url });
+}
@@ -0,0 +1,9 @@
+import { type NextRequest, NextResponse } from "next/server";
+import type { useReducer } from "react";
+type Reducer = typeof useReducer;
+let factory: lazy;
+export async function GET(request: NextRequest): Promise<Response> {
+    return NextResponse.json({
+        url: request.url
+    });
+}
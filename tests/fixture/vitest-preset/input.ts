@@ -0,0 +1,9 @@
+describe('counter', () => {
+  const spy: Mock = vi.fn();
+
+  beforeEach(() => spy.mockClear());
+
+  it('increments', () => {
+    expect(spy).not.toHaveBeenCalled();
+  });
+});
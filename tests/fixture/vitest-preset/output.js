@@ -0,0 +1,8 @@
+import { type Mock, beforeEach, describe, expect, it, vi } from "vitest";
+describe('counter', ()=>{
+    const spy: Mock = vi.fn();
+    beforeEach(()=>spy.mockClear());
+    it('increments', ()=>{
+        expect(spy).not.toHaveBeenCalled();
+    });
+});
@@ -0,0 +1,10 @@
+import { type Ref, computed, defineComponent, h, nextTick, onMounted, ref, watchEffect } from "vue";
+export default defineComponent({
+    setup () {
+        const count: Ref<number> = ref(0);
+        const doubled = computed(()=>count.value * 2);
+        watchEffect(()=>console.log(doubled.value));
+        onMounted(()=>nextTick(()=>console.log('Component mounted')));
+        return ()=>h('div', count.value);
+    }
+});
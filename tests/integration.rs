@@ -1,14 +1,15 @@
 use std::{path::PathBuf, sync::Arc};
 use swc_core::{
-    common::{Mark, SourceMap, GLOBALS},
+    common::{comments::SingleThreadedComments, FileName, Mark, SourceFile, SourceMap, GLOBALS},
     ecma::{
         ast::*,
         codegen::{text_writer::JsWriter, Config as CodegenConfig, Emitter},
         parser::{parse_file_as_module, Syntax, TsSyntax},
+        transforms::base::resolver,
         visit::VisitMutWith,
     },
 };
-use swc_plugin_auto_import::{AutoImportVisitor, PluginConfig};
+use swc_plugin_auto_import::{AutoImportVisitor, CachedConfig, PluginConfig, Pragmas};
 
 #[testing::fixture("tests/fixture/**/input.ts")]
 #[testing::fixture("tests/fixture/**/input.tsx")]
@@ -31,7 +32,261 @@ fn fixture(input: PathBuf) {
     let cm = Arc::new(SourceMap::default());
     let fm = cm.load_file(&input).expect("Failed to load input file");
 
+    // Globs see the path relative to the crate root, like SWC passes it relative to cwd
+    let filename = input
+        .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+        .unwrap_or(&input)
+        .to_string_lossy()
+        .replace('\\', "/");
+
     let is_tsx = input.to_string_lossy().ends_with(".tsx");
+    let (actual, _) = transform(&cm, &fm, is_tsx, config, Some(&filename));
+
+    // Compare output
+    if actual.trim() != expected.trim() {
+        panic!(
+            "\n\n========== EXPECTED ==========\n{}\n========== ACTUAL ==========\n{}\n",
+            expected.trim(),
+            actual.trim()
+        );
+    }
+}
+
+#[test]
+fn rsc_diagnostics() {
+    let config = serde_json::from_str::<PluginConfig>(
+        r#"{ "imports": ["react"], "rsc": true, "directives": { "use client": { "deny": ["useId"] } } }"#,
+    )
+    .unwrap();
+
+    let source = "const [count] = useState(0);\nconst id = useId();\nuseEffect(() => {});";
+    let cm = Arc::new(SourceMap::default());
+
+    // Modules without a directive may be shared with client components
+    let fm = cm.new_source_file(FileName::Anon.into(), source.to_string());
+    let (output, diagnostics) = transform(&cm, &fm, false, config.clone(), None);
+    assert!(output.starts_with("import { useEffect, useId, useState } from \"react\";"));
+    assert!(diagnostics.is_empty());
+
+    let fm = cm.new_source_file(FileName::Anon.into(), format!("'use server';\n{}", source));
+    let (_, diagnostics) = transform(&cm, &fm, false, config.clone(), None);
+    assert_eq!(
+        diagnostics,
+        vec![
+            r#"`useEffect` from `react` is not allowed in "use server" modules"#,
+            r#"`useState` from `react` is not allowed in "use server" modules"#,
+        ]
+    );
+
+    let fm = cm.new_source_file(FileName::Anon.into(), format!("'use client';\n{}", source));
+    let (_, diagnostics) = transform(&cm, &fm, false, config, None);
+    assert_eq!(
+        diagnostics,
+        vec![r#"`useId` from `react` is not allowed in "use client" modules"#]
+    );
+}
+
+#[test]
+fn directive_filters_match_export_names() {
+    let config = serde_json::from_str::<PluginConfig>(
+        r#"{
+            "imports": [{ "react": [["useState", "useSignal"]] }, { "next/image": [["default", "NextImage"]] }],
+            "directives": { "default": { "deny": ["useState", "NextImage"] } }
+        }"#,
+    )
+    .unwrap();
+
+    let source = "const [count] = useSignal(0);\nconst image = <NextImage />;";
+    let cm = Arc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Anon.into(), source.to_string());
+    let (_, diagnostics) = transform(&cm, &fm, true, config, None);
+    assert_eq!(
+        diagnostics,
+        vec![
+            "`NextImage` from `next/image` is not allowed in modules without a directive",
+            "`useSignal` from `react` is not allowed in modules without a directive",
+        ]
+    );
+}
+
+#[test]
+fn conflict_error() {
+    let config = serde_json::from_str::<PluginConfig>(
+        r#"{
+            "imports": ["@vueuse/core", { "ahooks": ["useDebounce"] }, { "lodash-es": ["debounce"] }],
+            "conflicts": "error"
+        }"#,
+    )
+    .unwrap();
+
+    let source = "const query = useDebounce(search);\nconst save = debounce(write);";
+    let cm = Arc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Anon.into(), source.to_string());
+    let (output, diagnostics) = transform(&cm, &fm, false, config, None);
+
+    assert!(output.starts_with("import { debounce } from \"lodash-es\";\nconst query"));
+    assert_eq!(
+        diagnostics,
+        vec!["`useDebounce` is provided by `@vueuse/core`, `ahooks` with the same priority, give one of them a higher `priority` or add it to `ignore`"]
+    );
+}
+
+#[test]
+fn conflict_error_with_priority() {
+    // The priority of the inline react entry only ranks `useState`
+    let config = serde_json::from_str::<PluginConfig>(
+        r#"{
+            "imports": [
+                "preact",
+                "react",
+                { "from": "react", "imports": ["useState"], "priority": 1 }
+            ],
+            "conflicts": "error"
+        }"#,
+    )
+    .unwrap();
+
+    let source = "const [count] = useState(0);\nuseEffect(() => {});";
+    let cm = Arc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Anon.into(), source.to_string());
+    let (output, diagnostics) = transform(&cm, &fm, false, config, None);
+
+    assert!(output.starts_with("import { useState } from \"react\";\nconst [count]"));
+    assert_eq!(
+        diagnostics,
+        vec!["`useEffect` is provided by `preact/hooks`, `react` with the same priority, give one of them a higher `priority` or add it to `ignore`"]
+    );
+}
+
+#[test]
+fn conflicting_aliases() {
+    let config = r#"{
+        "imports": [{ "react": [["useState", "x"], ["useEffect", "x"]] }],
+        "conflicts": "error"
+    }"#;
+
+    let cached = CachedConfig::parse(config);
+    let warnings: Vec<_> = cached
+        .normalized(None)
+        .warnings()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        warnings,
+        ["$.imports[0]: `x` from `react` is an alias of several exports: `useState`, `useEffect`"]
+    );
+    let import = &cached.normalized(None).imports()[0];
+    assert_eq!(
+        import.tied,
+        ["`react` (`useState`)", "`react` (`useEffect`)"]
+    );
+
+    let source = "const value = x();";
+    let cm = Arc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Anon.into(), source.to_string());
+    let config = serde_json::from_str::<PluginConfig>(config).unwrap();
+    let (output, diagnostics) = transform(&cm, &fm, false, config, None);
+
+    assert!(output.starts_with("const value"));
+    assert_eq!(
+        diagnostics,
+        vec!["`x` is provided by `react` (`useState`), `react` (`useEffect`) with the same priority, give one of them a higher `priority` or add it to `ignore`"]
+    );
+}
+
+#[test]
+fn normalization_warnings() {
+    let config = serde_json::from_str::<PluginConfig>(
+        r#"{
+            "imports": [
+                "react",
+                { "react": ["useState", ["useState", "useSignal"]] },
+                { "lodash": ["*", ["default", "_"], "kebab-case"] }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let visitor = AutoImportVisitor::new(config, Mark::root());
+    let warnings: Vec<_> = visitor.warnings().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        warnings,
+        [
+            "$.imports[2]: `*` from `lodash` needs an alias",
+            "$.imports[2]: `kebab-case` from `lodash` is not a valid identifier",
+            "$.imports[1]: `useState` from `react` is imported under several names: `useState`, `useSignal`",
+        ]
+    );
+
+    // Built-in presets are clean
+    let config = serde_json::from_str::<PluginConfig>(
+        r#"{
+            "imports": [
+                "react", "react-dom", "react-router", "@tanstack/react-query", "zustand", "jotai",
+                "vue", "vue-router", "pinia", "@vueuse/core", "next", "solid-js", "preact",
+                "svelte", "vitest", "jest"
+            ]
+        }"#,
+    )
+    .unwrap();
+    let visitor = AutoImportVisitor::new(config, Mark::root());
+    assert!(visitor.warnings().is_empty());
+}
+
+#[test]
+fn resolved_imports() {
+    let cached = CachedConfig::parse(
+        r#"{
+            "imports": [
+                { "from": "@acme/ui", "imports": ["Button", ["default", "Theme"]] },
+                { "from": "@acme/legacy", "imports": ["Button"], "priority": 1 },
+                { "from": "react", "type": true, "imports": ["FC"] }
+            ]
+        }"#,
+    );
+    let imports = cached.normalized(None).imports();
+    let import = |local: &str| imports.iter().find(|import| import.local == local).unwrap();
+
+    let locals: Vec<_> = imports.iter().map(|import| import.local.as_str()).collect();
+    assert_eq!(locals, ["Button", "FC", "Theme"]);
+    assert_eq!(import("Button").source, "@acme/legacy");
+    assert_eq!(import("Button").shadowed, ["@acme/ui"]);
+    assert_eq!(import("Theme").imported, "default");
+    assert!(import("FC").type_only);
+    assert!(import("FC").shadowed.is_empty());
+}
+
+#[test]
+fn file_filters() {
+    let config = serde_json::from_str::<PluginConfig>(
+        r#"{
+            "imports": ["react", { "preset": "vitest", "include": ["**/*.test.ts"] }],
+            "exclude": ["**/node_modules/**"]
+        }"#,
+    )
+    .unwrap();
+
+    let source = "describe('count', () => useState(0));";
+    let cm = Arc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Anon.into(), source.to_string());
+    let run = |filename| transform(&cm, &fm, false, config.clone(), filename).0;
+
+    assert!(run(Some("src/count.test.ts"))
+        .starts_with("import { useState } from \"react\";\nimport { describe } from \"vitest\";"));
+    assert!(run(Some("src/count.ts")).starts_with("import { useState } from \"react\";\ndescribe"));
+    assert!(run(Some("node_modules/count/count.test.ts")).starts_with("describe"));
+    // Without a filename no glob filter applies
+    assert!(run(None).contains("import { describe } from \"vitest\";"));
+}
+
+/// Run the plugin on a source file, returning the output and its diagnostics
+fn transform(
+    cm: &Arc<SourceMap>,
+    fm: &SourceFile,
+    is_tsx: bool,
+    config: PluginConfig,
+    filename: Option<&str>,
+) -> (String, Vec<String>) {
     let syntax = Syntax::Typescript(TsSyntax {
         tsx: is_tsx,
         decorators: false,
@@ -41,12 +296,19 @@ fn fixture(input: PathBuf) {
     });
 
     GLOBALS.set(&Default::default(), || {
-        let mut module = parse_file_as_module(&fm, syntax, EsVersion::Es2020, None, &mut vec![])
-            .expect("Failed to parse input");
+        let comments = SingleThreadedComments::default();
+        let mut module =
+            parse_file_as_module(fm, syntax, EsVersion::Es2020, Some(&comments), &mut vec![])
+                .expect("Failed to parse input");
 
-        // Apply transform
+        // Resolve scopes like the SWC host does before running plugins
         let unresolved_mark = Mark::new();
-        let mut visitor = AutoImportVisitor::new(config, unresolved_mark);
+        let top_level_mark = Mark::new();
+        module.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, true));
+
+        // Apply transform
+        let mut visitor = AutoImportVisitor::new_for_file(config, unresolved_mark, filename)
+            .with_pragmas(Pragmas::from_comments(&comments, &module));
         module.visit_mut_with(&mut visitor);
 
         // Generate output
@@ -63,13 +325,12 @@ fn fixture(input: PathBuf) {
 
         let actual = String::from_utf8(buf).expect("Invalid UTF-8");
 
-        // Compare output
-        if actual.trim() != expected.trim() {
-            panic!(
-                "\n\n========== EXPECTED ==========\n{}\n========== ACTUAL ==========\n{}\n",
-                expected.trim(),
-                actual.trim()
-            );
-        }
-    });
+        let diagnostics = visitor
+            .diagnostics()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        (actual, diagnostics)
+    })
 }